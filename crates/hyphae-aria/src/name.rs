@@ -21,6 +21,19 @@ fn get_css_pseudo_elt_content(element: &HtmlElement, pseudo: &str) -> Option<Str
     style.get_property_value("content").ok()
 }
 
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// Checks the node type instead of using `dyn_ref` so that elements from another window, such as
+/// an iframe, are still treated as elements.
+#[inline]
+fn as_element(node: &Node) -> Option<&Element> {
+    if Node::ELEMENT_NODE == node.node_type() {
+        Some(node.unchecked_ref())
+    } else {
+        None
+    }
+}
+
 #[inline]
 fn is_hidden_and_no_aria_idref_label(node: &Node) -> Result<bool, JsValue> {
     if let Some(element) = node.dyn_ref::<HtmlElement>() {
@@ -52,7 +65,7 @@ fn is_hidden_and_no_aria_idref_label(node: &Node) -> Result<bool, JsValue> {
 #[inline]
 #[allow(dead_code)]
 fn is_element_an_embedded_control(node: &Node) -> bool {
-    if let Some(element) = node.dyn_ref::<Element>() {
+    if let Some(element) = as_element(node) {
         matches!(
             element.tag_name().as_str(),
             "img"
@@ -77,7 +90,7 @@ fn is_element_an_embedded_control(node: &Node) -> bool {
 /// - none
 #[inline]
 fn is_presentational(node: &Node) -> bool {
-    node.dyn_ref::<Element>()
+    as_element(node)
         .and_then(|element| element.get_attribute("role"))
         .map(|value| matches!(value.as_str(), "presentation" | "none"))
         .unwrap_or_default()
@@ -162,9 +175,8 @@ fn element_accessible_name_impl(
 
     if !is_presentational(node) {
         if !is_albt {
-            if let Some(labelled_by) = node
-                .dyn_ref::<Element>()
-                .and_then(|element| element.get_attribute("aria-labelledby"))
+            if let Some(labelled_by) =
+                as_element(node).and_then(|element| element.get_attribute("aria-labelledby"))
             {
                 add_node_to_traversed(node, traversed);
                 let selector_ids = id_refs_to_query_string(labelled_by);
@@ -184,8 +196,7 @@ fn element_accessible_name_impl(
             }
         }
 
-        if let Some(label) = node
-            .dyn_ref::<Element>()
+        if let Some(label) = as_element(node)
             .and_then(|element| element.get_attribute("aria-label"))
             .map(|value| value.trim().to_owned())
         {
@@ -196,34 +207,40 @@ fn element_accessible_name_impl(
             };
         }
 
-        if let Some(node) = node.dyn_ref::<Element>() {
+        if let Some(node) = as_element(node) {
             // Text alternative info: https://www.w3.org/TR/html-aam-1.0/#accessible-name-and-description-computation
-            let name = match node.tag_name().to_lowercase().as_str() {
-                "input" => text_alternative_input(node.unchecked_ref(), traversed, is_albt)?,
-                "textarea" => text_alternative_label_title_placeholder(node, traversed, is_albt)?,
-                "button" => text_alternative_subtree_title(node, traversed, is_albt)?,
-                "fieldset" => {
-                    text_alternative_first_child_subtree_title(node, "legend", traversed, is_albt)?
-                }
-                "output" => text_alternative_subtree_title(node, traversed, is_albt)?,
-                "select" | "datalist" | "optgroup" | "option" | "keygen" | "progress" | "meter"
-                | "legend" => text_alternative_label_title(node, traversed, is_albt)?,
-                "summary" => text_alternative_summary(node, traversed, is_albt)?,
-                "figure" => text_alternative_first_child_subtree_title(
-                    node,
-                    "figcaption",
-                    traversed,
-                    is_albt,
-                )?,
-                "img" => {
-                    text_alternative_alt_title!(node as HtmlImageElement)
-                }
-                "table" => {
-                    text_alternative_first_child_subtree_title(node, "caption", traversed, is_albt)?
+            let name = if node.namespace_uri().as_deref() == Some(SVG_NAMESPACE) {
+                text_alternative_svg(node, traversed, is_albt)?
+            } else {
+                match node.tag_name().to_lowercase().as_str() {
+                    "input" => text_alternative_input(node.unchecked_ref(), traversed, is_albt)?,
+                    "textarea" => {
+                        text_alternative_label_title_placeholder(node, traversed, is_albt)?
+                    }
+                    "button" => text_alternative_subtree_title(node, traversed, is_albt)?,
+                    "fieldset" => text_alternative_first_child_subtree_title(
+                        node, "legend", traversed, is_albt,
+                    )?,
+                    "output" => text_alternative_subtree_title(node, traversed, is_albt)?,
+                    "select" | "datalist" | "optgroup" | "option" | "keygen" | "progress"
+                    | "meter" | "legend" => text_alternative_label_title(node, traversed, is_albt)?,
+                    "summary" => text_alternative_summary(node, traversed, is_albt)?,
+                    "figure" => text_alternative_first_child_subtree_title(
+                        node,
+                        "figcaption",
+                        traversed,
+                        is_albt,
+                    )?,
+                    "img" => {
+                        text_alternative_alt_title!(node as HtmlImageElement)
+                    }
+                    "table" => text_alternative_first_child_subtree_title(
+                        node, "caption", traversed, is_albt,
+                    )?,
+                    "a" => text_alternative_subtree_title(node, traversed, is_albt)?,
+                    "area" => text_alternative_alt_title!(node as HtmlAreaElement),
+                    _ => get_children_accessible_names(node, traversed, is_albt)?,
                 }
-                "a" => text_alternative_subtree_title(node, traversed, is_albt)?,
-                "area" => text_alternative_alt_title!(node as HtmlAreaElement),
-                _ => get_children_accessible_names(node, traversed, is_albt)?,
             };
             accumulated_text.push_str(&name);
         }
@@ -304,6 +321,21 @@ fn text_alternative_summary(
     }
 }
 
+/// SVG elements are named by a `title` child element and otherwise by their content, such as the
+/// text of a `text` element.
+fn text_alternative_svg(
+    element: &Element,
+    traversed: &mut Vec<Node>,
+    is_albt: bool,
+) -> Result<String, JsValue> {
+    let name = text_alternative_first_child_subtree_title(element, "title", traversed, is_albt)?;
+    if name.is_empty() {
+        get_children_accessible_names(element, traversed, is_albt)
+    } else {
+        Ok(name)
+    }
+}
+
 fn text_alternative_first_child_subtree_title(
     element: &Element,
     child_tag: &str,
@@ -314,9 +346,8 @@ fn text_alternative_first_child_subtree_title(
     let children = element.child_nodes();
     for i in 0..children.length() {
         let child = children.get(i).unwrap();
-        if child
-            .dyn_ref::<Element>()
-            .map(|element| element.tag_name() == child_tag)
+        if as_element(&child)
+            .map(|element| element.tag_name().eq_ignore_ascii_case(child_tag))
             .unwrap_or_default()
         {
             name = get_children_accessible_names(&child, traversed, is_albt)?;
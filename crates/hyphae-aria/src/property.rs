@@ -16,9 +16,11 @@ macro_rules! aria_property {
                 fn to_query_string(&self) -> String {
                     match self {
                         $(
-                            $enum_name::$var_name(value) => format!("[aria-{}={}]",
+                            // the value is quoted as it can have characters that aren't
+                            // valid in a CSS identifier, such as `aria-valuetext="50%"`
+                            $enum_name::$var_name(value) => format!("[aria-{}=\"{}\"]",
                                     stringify!($var_name).to_lowercase(),
-                                    value.to_query_string(),
+                                    value.to_query_string().replace('\\', "\\\\").replace('"', "\\\""),
                                 ),
                         )*
                     }
@@ -319,3 +321,41 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn property_selector_quotes_value() {
+        assert_eq!(
+            "[aria-haspopup=\"dialog\"]",
+            AriaProperty::HasPopup(HasPopupToken::Dialog).to_query_string()
+        );
+        assert_eq!(
+            "[aria-valuetext=\"50%\"]",
+            AriaProperty::ValueText("50%".to_owned()).to_query_string()
+        );
+        assert_eq!(
+            "[aria-level=\"2\"]",
+            AriaProperty::Level(2).to_query_string()
+        );
+    }
+
+    #[test]
+    fn property_selector_escapes_quotes_and_backslashes() {
+        assert_eq!(
+            r#"[aria-label="Say \"hi\" \\ wave"]"#,
+            AriaProperty::Label(r#"Say "hi" \ wave"#.to_owned()).to_query_string()
+        );
+    }
+
+    #[test]
+    fn token_is_lowercase() {
+        assert_eq!("listbox", HasPopupToken::ListBox.to_query_string());
+        assert_eq!(
+            "additionstext",
+            RelevantToken::AdditionsText.to_query_string()
+        );
+    }
+}
@@ -22,11 +22,16 @@ macro_rules! roles_impl {
                         $(
                             AriaRole::$var => {
                                 let queries: &[&str] = &[$($implicit,)?];
-                                if queries.is_empty() {
-                                    format!("[role={}]", $name)
-                                } else {
-                                    format!("[role={}],{}", $name, queries.join(","))
-                                }
+                                // an explicit role always overrides the implicit role of an element
+                                std::iter::once(format!("[role={}]", $name))
+                                    .chain(
+                                        queries
+                                            .iter()
+                                            .flat_map(|query| query.split(','))
+                                            .map(|query| format!("{}:not([role])", query.trim())),
+                                    )
+                                    .collect::<Vec<_>>()
+                                    .join(",")
                             }
                         )*
                     }
@@ -39,6 +44,11 @@ roles_impl! {
     /// Main indicator of type. This semantic association allows tools to present and support
     /// interaction with the object in a manner that is consistent with user expectations about
     /// other objects of that type.
+    ///
+    /// An element with an explicit `role` attribute will only match that role and not the implicit
+    /// role of the element, for example `<li role="tab">` is a [`AriaRole::Tab`] and not a
+    /// [`AriaRole::ListItem`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     pub enum AriaRole {
     /// `alert` role - no implicit elements with these semantics
     Alert, "alert", [],
@@ -51,6 +61,12 @@ roles_impl! {
     /// `article` role - implicit elements with these semantics:
     /// - `article`
     Article, "article", ["article"],
+    /// `banner` role - implicit elements with these semantics:
+    /// - `header` when not within an `article`, `aside`, `main`, `nav` or `section` element
+    Banner, "banner", ["header:not(article header):not(aside header):not(main header):not(nav header):not(section header)"],
+    /// `blockquote` role - implicit elements with these semantics:
+    /// - `blockquote`
+    Blockquote, "blockquote", ["blockquote"],
     /// `button` role - implicit elements with these semantics:
     /// - `button`
     /// - `input` with types of:
@@ -63,6 +79,9 @@ roles_impl! {
     /// `checkbox` role - implicit elements with these semantics:
     /// - `input` with `type=checkbox`
     Checkbox, "checkbox", ["input[type=checkbox]"],
+    /// `code` role - implicit elements with these semantics:
+    /// - `code`
+    Code, "code", ["code"],
     /// `combobox` role - implicit elements with these semantics:
     /// - `input` with `list` attribute and types:
     ///     - `text`
@@ -80,18 +99,41 @@ roles_impl! {
         "input[type=email][list]",
         "select"
         ],
+    /// `columnheader` role - implicit elements with these semantics:
+    /// - `th` within a `thead` element
+    /// - `th` with `scope=col`
+    ColumnHeader, "columnheader", ["thead th", "th[scope=col]"],
     /// `complementary` role - implicit elements with these semantics:
     /// - `aside`
     Complementary, "complementary", ["aside"],
+    /// `contentinfo` role - implicit elements with these semantics:
+    /// - `footer` when not within an `article`, `aside`, `main`, `nav` or `section` element
+    ContentInfo, "contentinfo", ["footer:not(article footer):not(aside footer):not(main footer):not(nav footer):not(section footer)"],
+    /// `deletion` role - implicit elements with these semantics:
+    /// - `del`
+    /// - `s`
+    Deletion, "deletion", ["del", "s"],
     /// `dialog` role - implicit elements with these semantics:
     /// - `dialog`
     Dialog, "dialog", ["dialog"],
+    /// `emphasis` role - implicit elements with these semantics:
+    /// - `em`
+    Emphasis, "emphasis", ["em"],
     /// `figure` role - implicit elements with these semantics:
     /// - `figure`
     Figure, "figure", ["figure"],
     /// `form` role - implicit elements with these semantics:
     /// - `form` - regardless of accessible name (differs from w3)
     Form, "form", ["form"],
+    /// `grid` role - no implicit elements with these semantics
+    Grid, "grid", [],
+    /// `gridcell` role - no implicit elements with these semantics
+    GridCell, "gridcell", [],
+    /// `group` role - implicit elements with these semantics:
+    /// - `details`
+    /// - `fieldset`
+    /// - `optgroup`
+    Group, "group", ["details", "fieldset", "optgroup"],
     /// `heading` role - implicit elements with these semantics:
     /// - `h1`
     /// - `h2`
@@ -103,6 +145,9 @@ roles_impl! {
     /// `img` role - implicit elements with these semantics:
     /// - `img`
     Image, "img", ["img"],
+    /// `insertion` role - implicit elements with these semantics:
+    /// - `ins`
+    Insertion, "insertion", ["ins"],
     /// `link` role - implicit elements with these semantics:
     /// - `a` with `href`
     /// - `area` with `href`
@@ -124,6 +169,9 @@ roles_impl! {
     /// `main` role - implicit elements with these semantics:
     /// - `main`
     Main, "main", ["main"],
+    /// `mark` role - implicit elements with these semantics:
+    /// - `mark`
+    Mark, "mark", ["mark"],
     /// `math` role - implicit elements with these semantics:
     /// - `math`
     Math, "math", ["math"],
@@ -135,11 +183,15 @@ roles_impl! {
     MenuItemCheckbox, "menuitemcheckbox", [],
     /// `menuitemcheckbox` role - no implicit elements with these semantics
     MenuItemRadio, "menuitemradio", [],
+    /// `meter` role - implicit elements with these semantics:
+    ///  - `meter`
+    Meter, "meter", ["meter"],
     /// `navigation` role - implicit elements with these semantics:
     ///  - `nav`
     Navigation, "navigation", ["nav"],
-    /// `none` role - no implicit elements with these semantics
-    None, "none", [],
+    /// `none` role - synonym of `presentation`, implicit elements with these semantics:
+    ///  - `img` with alt="" (empty string)
+    None, "none", ["img[alt=\"\"]"],
     /// `note` role - no implicit elements with these semantics
     Note, "note", [],
     /// `option` role - implicit elements with these semantics:
@@ -148,9 +200,9 @@ roles_impl! {
     /// `status` role - implicit elements with these semantics:
     ///  - `output`
     Output, "status", ["output"],
-    /// `presentation` role - implicit elements with these semantics:
+    /// `presentation` role - synonym of `none`, implicit elements with these semantics:
     ///  - `img` with alt="" (empty string)
-    Presentation, "presentation", ["img[alt=``]"],
+    Presentation, "presentation", ["img[alt=\"\"]"],
     /// `progressbar` role - implicit elements with these semantics:
     ///  - `progress`
     Progressbar, "progressbar", ["progress"],
@@ -184,6 +236,15 @@ roles_impl! {
     /// `spinbutton` role - implicit elements with these semantics:
     ///  - `input` with `type=number`
     SpinButton, "spinbutton", ["input[type=number]"],
+    /// `strong` role - implicit elements with these semantics:
+    /// - `strong`
+    Strong, "strong", ["strong"],
+    /// `subscript` role - implicit elements with these semantics:
+    /// - `sub`
+    Subscript, "subscript", ["sub"],
+    /// `superscript` role - implicit elements with these semantics:
+    /// - `sup`
+    Superscript, "superscript", ["sup"],
     /// `switch` role - no implicit elements with these semantics
     Switch, "switch", [],
     /// `tab` role - no implicit elements with these semantics
@@ -205,11 +266,51 @@ roles_impl! {
     ///    - `url`
     /// - `textarea`
     TextBox, "textbox", ["input:not([type])", "input[type=email]", "input[type=tel]", "input[type=text]", "input[type=url]", "textarea"],
+    /// `time` role - implicit elements with these semantics:
+    /// - `time`
+    Time, "time", ["time"],
     /// `toolbar` role - no implicit elements with these semantics
     Toolbar, "toolbar", [],
     /// `tooltip` role - no implicit elements with these semantics
     Tooltip, "tooltip", [],
+    /// `treegrid` role - no implicit elements with these semantics
+    TreeGrid, "treegrid", [],
     /// `treeitem` role - no implicit elements with these semantics
     TreeItem, "treeitem", [],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_only_role_selector() {
+        assert_eq!("[role=tab]", AriaRole::Tab.to_query_string());
+        assert_eq!("[role=grid]", AriaRole::Grid.to_query_string());
+    }
+
+    #[test]
+    fn implicit_role_selectors_exclude_explicit_roles() {
+        assert_eq!(
+            "[role=checkbox],input[type=checkbox]:not([role])",
+            AriaRole::Checkbox.to_query_string()
+        );
+        // comma separated implicit selectors are each excluded
+        assert_eq!(
+            "[role=button],button:not([role]),input[type=button]:not([role]),\
+            input[type=img]:not([role]),input[type=reset]:not([role]),\
+            input[type=submit]:not([role]),summary:not([role])",
+            AriaRole::Button.to_query_string()
+        );
+    }
+
+    #[test]
+    fn nested_header_is_not_banner() {
+        assert_eq!(
+            "[role=banner],header:not(article header):not(aside header):not(main header)\
+            :not(nav header):not(section header):not([role])",
+            AriaRole::Banner.to_query_string()
+        );
+    }
+}
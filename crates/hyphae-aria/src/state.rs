@@ -51,7 +51,7 @@ fn state_disabled(state: &bool) -> String {
 
 fn state_hidden(state: &DuoState) -> String {
     if *state == DuoState::True {
-        ":hidden,".to_string()
+        "[hidden],".to_string()
     } else {
         String::new()
     }
@@ -92,7 +92,7 @@ aria_state! {
         /// Indicates whether the element is exposed to an accessibility API.
         ///
         /// Attribute parity:
-        /// - any element which is hidden will be equivalent to aria-hidden="true"
+        /// - any element with the `hidden` attribute will be equivalent to aria-hidden="true"
         Hidden(DuoState) => state_hidden,
         /// Indicates the entered value does not conform to the format expected by the
         /// application.
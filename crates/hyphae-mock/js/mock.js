@@ -1,6 +1,17 @@
+function install_fetch_mock(respond) {
+	let controller = {
+		original_fetch: fetch,
+		requests: [],
+	};
+	fetch = (input) => {
+		controller.requests.push(typeof input === `string` ? input : input.url);
+		return respond();
+	};
+	return controller;
+}
+
 export function mock_fetch_resolve(value) {
-	let original_fetch = fetch;
-	fetch = () => {
+	return install_fetch_mock(() => {
 		if (typeof value === "object") {
 			value = new Blob([JSON.stringify(value, null, 2)]);
 		}
@@ -9,8 +20,30 @@ export function mock_fetch_resolve(value) {
 				status: 200,
 			}))
 		});
-	};
-	return original_fetch;
+	});
+}
+
+export function mock_fetch_stream(chunks, delay_ms) {
+	return install_fetch_mock(() => {
+		const encoder = new TextEncoder();
+		let index = 0;
+		const body = new ReadableStream({
+			pull(stream) {
+				return new Promise((resolve) => {
+					setTimeout(() => {
+						if (index < chunks.length) {
+							stream.enqueue(encoder.encode(chunks[index]));
+							index += 1;
+						} else {
+							stream.close();
+						}
+						resolve();
+					}, index < chunks.length ? delay_ms : 0);
+				});
+			}
+		});
+		return Promise.resolve(new Response(body, { status: 200 }));
+	});
 }
 
 export function mock_fetch_error(code, reason) {
@@ -19,8 +52,7 @@ export function mock_fetch_error(code, reason) {
 			reject(reason);
 		})
 	};
-	let original_fetch = fetch;
-	fetch = () => {
+	return install_fetch_mock(() => {
 		const blob = new Blob([JSON.stringify(reason, null, 2)]);
 		return new Promise((resolve) => {
 			let resp = new Response(blob, { status: code });
@@ -33,13 +65,133 @@ export function mock_fetch_error(code, reason) {
 				resp
 			)
 		})
+	});
+}
+
+export function mock_fetch_network_error() {
+	return install_fetch_mock(() => {
+		// a network failure rejects with a TypeError rather than resolving an error Response
+		return Promise.reject(new TypeError(`Failed to fetch`));
+	});
+}
+
+export function wait_for_fetch_request(controller, timeout) {
+	return new Promise((resolve, reject) => {
+		const start = Date.now();
+		const check = () => {
+			if (controller.requests.length > 0) {
+				resolve();
+			} else if (Date.now() - start >= timeout) {
+				reject(`No fetch request was made within the allotted time: ${timeout}ms.`);
+			} else {
+				setTimeout(check, 1);
+			}
+		};
+		check();
+	});
+}
+
+export function restore_fetch(controller) {
+	fetch = controller.original_fetch;
+}
+
+export function mock_console() {
+	let mock_controller = {
+		errors: [],
+		warnings: [],
+		original_error: console.error,
+		original_warn: console.warn,
+		restore: () => {
+			console.error = mock_controller.original_error;
+			console.warn = mock_controller.original_warn;
+		}
+	};
+
+	console.error = (...args) => {
+		mock_controller.errors.push(args.map(String).join(` `));
+	};
+	console.warn = (...args) => {
+		mock_controller.warnings.push(args.map(String).join(` `));
+	};
+
+	return mock_controller;
+}
+
+export function mock_media_query(query, matches) {
+	const original_match_media = window.matchMedia;
+
+	class MockMediaQueryList extends EventTarget {
+		constructor() {
+			super();
+			this.media = query;
+			this.matches = matches;
+			this.onchange = null;
+		}
+
+		// deprecated but still used by some libraries
+		addListener(listener) {
+			this.addEventListener(`change`, listener);
+		}
+
+		removeListener(listener) {
+			this.removeEventListener(`change`, listener);
+		}
+	}
+
+	const list = new MockMediaQueryList();
+	window.matchMedia = (media) => {
+		return media.trim() === query.trim() ? list : original_match_media.call(window, media);
+	};
+
+	return {
+		get matches() {
+			return list.matches;
+		},
+		set_matches: (matches) => {
+			if (list.matches === matches) {
+				return;
+			}
+			list.matches = matches;
+			const event = new MediaQueryListEvent(`change`, {
+				matches: matches,
+				media: query,
+			});
+			list.dispatchEvent(event);
+			if (list.onchange) {
+				list.onchange(event);
+			}
+		},
+		restore: () => {
+			window.matchMedia = original_match_media;
+		}
 	};
-	return original_fetch;
 }
 
+export function mock_visibility(state) {
+	let mock_controller = {
+		state: state,
+		set: (state) => {
+			mock_controller.state = state;
+			document.dispatchEvent(new Event(`visibilitychange`, { bubbles: true }));
+		},
+		restore: () => {
+			// the own properties shadow the getters of Document.prototype
+			delete document.visibilityState;
+			delete document.hidden;
+		}
+	};
+
+	Object.defineProperty(document, `visibilityState`, {
+		configurable: true,
+		get: () => mock_controller.state,
+	});
+	Object.defineProperty(document, `hidden`, {
+		configurable: true,
+		get: () => mock_controller.state !== `visible`,
+	});
+	mock_controller.set(state);
 
-export function restore_fetch(original_fetch) {
-	fetch = original_fetch;
+	return mock_controller;
 }
 
 export function mock_websocket(conn_delay) {
@@ -5,17 +5,31 @@
 //!
 //! _Work in Progress_
 
-use js_sys::Uint8Array;
+use std::time::Duration;
+
+use js_sys::{Array, Promise, Uint8Array};
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
 
 #[wasm_bindgen(module = "/js/mock.js")]
 extern "C" {
-    fn mock_fetch_resolve(value: JsValue) -> JsValue;
-    fn mock_fetch_error(code: JsValue, reason: JsValue) -> JsValue;
-    fn restore_fetch(original_fetch: &JsValue);
+    fn mock_fetch_resolve(value: JsValue) -> RawFetchController;
+    fn mock_fetch_error(code: JsValue, reason: JsValue) -> RawFetchController;
+    #[wasm_bindgen(js_name = mock_fetch_stream)]
+    fn mock_fetch_stream_raw(chunks: Array, delay_ms: JsValue) -> RawFetchController;
+    #[wasm_bindgen(js_name = mock_fetch_network_error)]
+    fn mock_fetch_network_error_raw() -> RawFetchController;
+    fn wait_for_fetch_request(controller: &RawFetchController, timeout: JsValue) -> Promise;
+    fn restore_fetch(controller: &RawFetchController);
+
+    type RawFetchController;
+    #[wasm_bindgen(method, getter = requests)]
+    fn requests(this: &RawFetchController) -> Array;
 
     fn mock_websocket(conn_delay: JsValue) -> RawWebSocketController;
+    #[wasm_bindgen(js_name = mock_console)]
+    fn mock_console_raw() -> RawConsoleController;
 
     type RawWebSocketController;
     #[wasm_bindgen(method, getter = is_opened)]
@@ -36,6 +50,33 @@ extern "C" {
     #[wasm_bindgen(method)]
     fn restore(this: &RawWebSocketController);
 
+    type RawConsoleController;
+    #[wasm_bindgen(method, getter = errors)]
+    fn errors(this: &RawConsoleController) -> Array;
+    #[wasm_bindgen(method, getter = warnings)]
+    fn warnings(this: &RawConsoleController) -> Array;
+
+    #[wasm_bindgen(method, js_name = restore)]
+    fn restore_console(this: &RawConsoleController);
+
+    #[wasm_bindgen(js_name = mock_media_query)]
+    fn mock_media_query_raw(query: &str, matches: bool) -> RawMediaQueryController;
+
+    type RawMediaQueryController;
+    #[wasm_bindgen(method, getter = matches)]
+    fn matches(this: &RawMediaQueryController) -> bool;
+    #[wasm_bindgen(method)]
+    fn set_matches(this: &RawMediaQueryController, matches: bool);
+    #[wasm_bindgen(method, js_name = restore)]
+    fn restore_media_query(this: &RawMediaQueryController);
+
+    fn mock_visibility(state: &str) -> RawVisibilityController;
+
+    type RawVisibilityController;
+    #[wasm_bindgen(method, js_name = set)]
+    fn set_visibility_state(this: &RawVisibilityController, state: &str);
+    #[wasm_bindgen(method, js_name = restore)]
+    fn restore_visibility(this: &RawVisibilityController);
 }
 
 // @TODO: Provide a typed interface to avoid users having to deal with JsValue
@@ -139,11 +180,217 @@ pub fn mock_ws(conn_delay: u32) -> WebSocketController {
     WebSocketController(mock_websocket(conn_delay.into()))
 }
 
+/// Guard that records the messages logged with `console.error` and `console.warn`.
+///
+/// While the guard is alive the messages are captured instead of being logged, use [`errors`] and
+/// [`warnings`] to assert on them.
+///
+/// Note: When this is dropped the original `console.error` and `console.warn` functions are restored.
+///
+/// [`errors`]: ConsoleGuard::errors
+/// [`warnings`]: ConsoleGuard::warnings
+#[must_use]
+pub struct ConsoleGuard(RawConsoleController);
+
+impl ConsoleGuard {
+    /// Messages logged with `console.error` since this guard was created.
+    pub fn errors(&self) -> Vec<String> {
+        array_to_strings(self.0.errors())
+    }
+
+    /// Messages logged with `console.warn` since this guard was created.
+    pub fn warnings(&self) -> Vec<String> {
+        array_to_strings(self.0.warnings())
+    }
+}
+
+impl Drop for ConsoleGuard {
+    fn drop(&mut self) {
+        self.0.restore_console();
+    }
+}
+
+fn array_to_strings(array: Array) -> Vec<String> {
+    array.iter().filter_map(|value| value.as_string()).collect()
+}
+
+/// Replaces `console.error` and `console.warn` with versions that record each message and returns
+/// a guard to access the recorded messages.
+///
+/// # Examples
+///
+/// Assert that no errors were logged:
+/// ```no_run
+/// use hyphae_mock::ConsoleGuard;
+///
+/// let guard: ConsoleGuard = hyphae_mock::mock_console();
+/// // .. render and interact with the app
+/// assert!(guard.errors().is_empty());
+/// ```
+pub fn mock_console() -> ConsoleGuard {
+    ConsoleGuard(mock_console_raw())
+}
+
+/// Controller for a mock media query.
+///
+/// Use this controller to change whether the mock media query matches, which fires a `change`
+/// event on the `MediaQueryList` returned by `window.matchMedia`.
+///
+/// Note: When this is dropped the original `window.matchMedia` function is restored.
+#[must_use]
+pub struct MediaQueryController(RawMediaQueryController);
+
+impl MediaQueryController {
+    /// True, when the mock media query matches.
+    pub fn matches(&self) -> bool {
+        self.0.matches()
+    }
+
+    /// Set whether the mock media query matches.
+    ///
+    /// A `change` event is only fired when this changes the value of `matches`, as a browser only
+    /// fires the event when the result of the media query changes.
+    pub fn set_matches(&self, matches: bool) {
+        self.0.set_matches(matches);
+    }
+}
+
+impl Drop for MediaQueryController {
+    fn drop(&mut self) {
+        self.0.restore_media_query();
+    }
+}
+
+/// Replaces `window.matchMedia` so that the `query` given returns a mock `MediaQueryList`, and
+/// returns a controller for the mock.
+///
+/// The viewport of a headless browser is fixed, so this lets you test responsive components
+/// that read `window.matchMedia` for a breakpoint. Any other query is passed to the original
+/// `window.matchMedia`.
+///
+/// # Examples
+/// ```no_run
+/// use hyphae_mock::MediaQueryController;
+///
+/// let controller: MediaQueryController = hyphae_mock::mock_media_query("(max-width: 600px)", false);
+/// // .. render the app, which shows the desktop layout
+/// controller.set_matches(true);
+/// // .. the app has received a `change` event and shows the mobile layout
+/// ```
+pub fn mock_media_query(query: &str, matches: bool) -> MediaQueryController {
+    MediaQueryController(mock_media_query_raw(query, matches))
+}
+
+/// Controller for a mock `document.visibilityState`.
+///
+/// Use this controller to change the visibility of the page, such as when the user switches to
+/// another tab and back again.
+///
+/// Note: When this is dropped the original `document.visibilityState` and `document.hidden` are
+/// restored.
+#[must_use]
+pub struct VisibilityController(RawVisibilityController);
+
+impl VisibilityController {
+    /// Set `document.visibilityState` to the `state` given, such as `"hidden"` or `"visible"`,
+    /// and dispatch a `visibilitychange` event on the document.
+    ///
+    /// `document.hidden` is `true` for any state other than `"visible"`.
+    pub fn set(&self, state: &str) {
+        self.0.set_visibility_state(state);
+    }
+}
+
+impl Drop for VisibilityController {
+    fn drop(&mut self) {
+        self.0.restore_visibility();
+    }
+}
+
+/// Sets `document.visibilityState` to the `state` given, dispatching a `visibilitychange` event
+/// on the document, and returns a controller to change it again.
+///
+/// A headless browser always reports the page as visible, so this lets you test components that
+/// pause work, such as polling, while the page is hidden.
+///
+/// # Examples
+/// ```no_run
+/// use hyphae_mock::VisibilityController;
+///
+/// // .. render the app, which starts polling
+/// let controller: VisibilityController = hyphae_mock::set_visibility("hidden");
+/// // .. the app has received a `visibilitychange` event and stopped polling
+/// controller.set("visible");
+/// // .. the app has started polling again
+/// ```
+pub fn set_visibility(state: &str) -> VisibilityController {
+    VisibilityController(mock_visibility(state))
+}
+
 /// A handle that keeps the current fetch mock living.
 ///
 /// When this handle is dropped the original fetch API will be restored.
 #[must_use]
-pub struct FetchMockHandle(JsValue);
+pub struct FetchMockHandle(RawFetchController);
+
+impl FetchMockHandle {
+    /// The URLs of the requests made to the mocked Fetch API, in the order they were made.
+    pub fn requests(&self) -> Vec<String> {
+        array_to_strings(self.0.requests())
+    }
+
+    /// The number of requests made to the mocked Fetch API.
+    ///
+    /// Useful to catch double-submit bugs, where one action should only call the API once.
+    pub fn call_count(&self) -> usize {
+        self.0.requests().length() as usize
+    }
+
+    /// The number of requests made to the mocked Fetch API with a URL that matches the predicate.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// let handle = hyphae_mock::mock_fetch(Ok(&true));
+    /// // .. click the save button twice
+    /// assert_eq!(1, handle.call_count_for(|url| url.ends_with("/save")));
+    /// ```
+    pub fn call_count_for<F>(&self, url_predicate: F) -> usize
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.requests()
+            .iter()
+            .filter(|url| url_predicate(url))
+            .count()
+    }
+
+    /// Waits until at least one request has been made to the mocked Fetch API.
+    ///
+    /// This avoids guessing how long to wait after an action that should call the Fetch API.
+    ///
+    /// # Panics
+    /// If no request is made within the `timeout` given.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use wasm_bindgen_test::*;
+    ///
+    /// #[wasm_bindgen_test]
+    /// async fn wait_for_items_request() {
+    ///     let handle = hyphae_mock::mock_fetch(Ok(&vec!["Gardening"]));
+    ///     // .. click the button that loads the items
+    ///     handle.wait_for_request(Duration::from_millis(500)).await;
+    ///     assert_eq!(vec!["/api/items".to_owned()], handle.requests());
+    /// }
+    /// ```
+    pub async fn wait_for_request(&self, timeout: Duration) {
+        let timeout = (timeout.as_millis() as f64).into();
+        JsFuture::from(wait_for_fetch_request(&self.0, timeout))
+            .await
+            .unwrap_throw();
+    }
+}
 
 impl Drop for FetchMockHandle {
     fn drop(&mut self) {
@@ -204,6 +451,66 @@ where
     FetchMockHandle(fetch)
 }
 
+/// Mocks the Fetch API to return a Response with a body that streams the chunks given over time.
+///
+/// Each chunk is sent as UTF-8 bytes `delay_ms` after the previous chunk was read, and the body
+/// is closed after the last chunk. This is useful for testing components that read the
+/// [`ReadableStream`](https://rustwasm.github.io/wasm-bindgen/api/web_sys/struct.ReadableStream.html)
+/// body of a Response incrementally and render the content as it arrives.
+///
+/// # Examples
+/// ```
+/// use wasm_bindgen_test::*;
+/// use wasm_bindgen::JsCast;
+/// use wasm_bindgen_futures::JsFuture;
+/// use web_sys::{window, Response};
+///
+/// #[wasm_bindgen_test]
+/// async fn mock_fetch_stream_text() {
+///     // Hold handle to keep mock alive
+///     let _handle = hyphae_mock::mock_fetch_stream(vec!["Hello, ".into(), "World!".into()], 10);
+///     let window = window().expect("No global window");
+///     let resp: Response = JsFuture::from(window.fetch_with_str("/api/stream"))
+///         .await
+///         .unwrap()
+///         .unchecked_into();
+///     // reading the whole body waits for every chunk
+///     let text = JsFuture::from(resp.text().unwrap()).await.unwrap();
+///
+///     assert_eq!("Hello, World!", text.as_string().unwrap());
+/// }
+/// ```
+pub fn mock_fetch_stream(chunks: Vec<String>, delay_ms: u32) -> FetchMockHandle {
+    let chunks = chunks.into_iter().map(JsValue::from).collect::<Array>();
+    FetchMockHandle(mock_fetch_stream_raw(chunks, delay_ms.into()))
+}
+
+/// Mocks the Fetch API to fail as if there was a network error.
+///
+/// Unlike using [`mock_fetch`] with an [`Err`], which resolves to a [`Response`](web_sys::Response)
+/// with an error status, any calls to the fetch API will return a rejected Promise with a
+/// `TypeError` - this is how fetch behaves when the user is offline or the server can't be reached.
+///
+/// # Examples
+/// ```
+/// use wasm_bindgen_test::*;
+/// use wasm_bindgen_futures::JsFuture;
+/// use web_sys::window;
+///
+/// #[wasm_bindgen_test]
+/// async fn mock_fetch_offline() {
+///     // Hold handle to keep mock alive
+///     let _handle = hyphae_mock::mock_fetch_network_error();
+///     let window = window().expect("No global window");
+///     let result = JsFuture::from(window.fetch_with_str("someurl")).await;
+///
+///     assert!(result.is_err());
+/// }
+/// ```
+pub fn mock_fetch_network_error() -> FetchMockHandle {
+    FetchMockHandle(mock_fetch_network_error_raw())
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -213,7 +520,9 @@ mod tests {
     use wasm_bindgen::JsCast;
     use wasm_bindgen_futures::JsFuture;
     use wasm_bindgen_test::*;
-    use web_sys::{window, MessageEvent, Response, WebSocket};
+    use web_sys::{
+        window, HtmlElement, MessageEvent, ReadableStreamDefaultReader, Response, WebSocket,
+    };
     wasm_bindgen_test_configure!(run_in_browser);
 
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -268,6 +577,228 @@ mod tests {
         };
     }
 
+    #[wasm_bindgen_test]
+    async fn mock_fetch_network_error_rejects() {
+        let _handle = mock_fetch_network_error();
+        let window = window().expect("No global window");
+        let result = JsFuture::from(window.fetch_with_str("url_while_offline")).await;
+
+        match result {
+            Ok(_) => panic!("fetch should have been rejected as if there was a network error!"),
+            Err(error) => assert!(error.is_instance_of::<js_sys::TypeError>()),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn mock_fetch_stream_renders_chunks_as_they_arrive() {
+        let _handle = mock_fetch_stream(
+            vec!["Hello".to_owned(), ", ".to_owned(), "World!".to_owned()],
+            20,
+        );
+        let document = window().unwrap().document().unwrap();
+        let output: HtmlElement = document.create_element("output").unwrap().unchecked_into();
+        document.body().unwrap().append_child(&output).unwrap();
+
+        let resp: Response = JsFuture::from(window().unwrap().fetch_with_str("/api/stream"))
+            .await
+            .unwrap()
+            .unchecked_into();
+        let reader: ReadableStreamDefaultReader =
+            resp.body().unwrap().get_reader().unchecked_into();
+
+        // the text rendered after each chunk is read
+        let mut rendered = vec![];
+        loop {
+            let result = JsFuture::from(reader.read()).await.unwrap();
+            let done = js_sys::Reflect::get(&result, &"done".into()).unwrap();
+            if done.is_truthy() {
+                break;
+            }
+            let value = js_sys::Reflect::get(&result, &"value".into()).unwrap();
+            let chunk = String::from_utf8(Uint8Array::new(&value).to_vec()).unwrap();
+            output.append_with_str_1(&chunk).unwrap();
+            rendered.push(output.text_content().unwrap());
+        }
+
+        assert_eq!(vec!["Hello", "Hello, ", "Hello, World!"], rendered);
+
+        output.remove();
+    }
+
+    #[wasm_bindgen_test]
+    async fn wait_for_request_after_click() {
+        let handle = mock_fetch(Ok(&SomeObject { value: 1 }));
+        let document = window().unwrap().document().unwrap();
+        let button: HtmlElement = document.create_element("button").unwrap().unchecked_into();
+        document.body().unwrap().append_child(&button).unwrap();
+
+        let on_click = Closure::wrap(Box::new(move || {
+            // the request is made after a delay, like a debounced search
+            wasm_bindgen_futures::spawn_local(async {
+                hyphae_utils::wait_ms(20).await;
+                let _ = window().unwrap().fetch_with_str("/api/items");
+            });
+        }) as Box<dyn Fn()>);
+        button
+            .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+            .unwrap();
+
+        button.click();
+        assert!(handle.requests().is_empty());
+
+        handle.wait_for_request(Duration::from_millis(500)).await;
+        assert_eq!(vec!["/api/items".to_owned()], handle.requests());
+
+        button.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn double_click_save_is_deduped_to_one_call() {
+        let handle = mock_fetch(Ok(&SomeObject { value: 1 }));
+        let document = window().unwrap().document().unwrap();
+        let button: HtmlElement = document.create_element("button").unwrap().unchecked_into();
+        document.body().unwrap().append_child(&button).unwrap();
+
+        let saving = std::rc::Rc::new(std::cell::Cell::new(false));
+        let on_click = Closure::wrap(Box::new(move || {
+            // ignore clicks while a save is in progress
+            if !saving.replace(true) {
+                let _ = window().unwrap().fetch_with_str("/api/save");
+            }
+        }) as Box<dyn Fn()>);
+        button
+            .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+            .unwrap();
+
+        let _ = window().unwrap().fetch_with_str("/api/items");
+        button.click();
+        button.click();
+
+        assert_eq!(2, handle.call_count());
+        assert_eq!(1, handle.call_count_for(|url| url == "/api/save"));
+        assert_eq!(0, handle.call_count_for(|url| url == "/api/delete"));
+
+        button.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn console_guard_captures_errors_and_warnings() {
+        let guard = mock_console();
+
+        web_sys::console::error_1(&"Something went wrong!".into());
+        web_sys::console::warn_1(&"Careful now".into());
+
+        assert_eq!(vec!["Something went wrong!".to_owned()], guard.errors());
+        assert_eq!(vec!["Careful now".to_owned()], guard.warnings());
+    }
+
+    #[wasm_bindgen_test]
+    fn media_query_change_toggles_mobile_layout() {
+        let controller = mock_media_query("(max-width: 600px)", false);
+        let window = window().unwrap();
+        let document = window.document().unwrap();
+        let layout: HtmlElement = document.create_element("div").unwrap().unchecked_into();
+        document.body().unwrap().append_child(&layout).unwrap();
+
+        let query = window.match_media("(max-width: 600px)").unwrap().unwrap();
+        let render = {
+            let layout = layout.clone();
+            let query = query.clone();
+            move || {
+                let class = if query.matches() { "mobile" } else { "desktop" };
+                layout.set_class_name(class);
+            }
+        };
+        render();
+        let on_change = Closure::wrap(Box::new(render) as Box<dyn Fn()>);
+        query
+            .add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+            .unwrap();
+
+        assert!(!controller.matches());
+        assert_eq!("desktop", layout.class_name());
+
+        controller.set_matches(true);
+        assert!(controller.matches());
+        assert_eq!("mobile", layout.class_name());
+
+        controller.set_matches(false);
+        assert_eq!("desktop", layout.class_name());
+
+        layout.remove();
+    }
+
+    #[wasm_bindgen_test]
+    async fn hidden_page_pauses_polling() {
+        use std::{cell::Cell, rc::Rc};
+
+        let window = window().unwrap();
+        let document = window.document().unwrap();
+
+        let polls = Rc::new(Cell::new(0));
+        let poll = {
+            let polls = polls.clone();
+            Closure::wrap(Box::new(move || polls.set(polls.get() + 1)) as Box<dyn Fn()>)
+        };
+        let interval = Rc::new(Cell::new(None));
+        let start = {
+            let interval = interval.clone();
+            let poll: js_sys::Function = poll.as_ref().unchecked_ref::<js_sys::Function>().clone();
+            move || {
+                let id = web_sys::window()
+                    .unwrap()
+                    .set_interval_with_callback_and_timeout_and_arguments_0(&poll, 10)
+                    .unwrap();
+                interval.set(Some(id));
+            }
+        };
+        start();
+
+        let on_visibility_change = {
+            let interval = interval.clone();
+            Closure::wrap(Box::new(move || {
+                let window = web_sys::window().unwrap();
+                if window.document().unwrap().hidden() {
+                    if let Some(id) = interval.take() {
+                        window.clear_interval_with_handle(id);
+                    }
+                } else if interval.get().is_none() {
+                    start();
+                }
+            }) as Box<dyn Fn()>)
+        };
+        document
+            .add_event_listener_with_callback(
+                "visibilitychange",
+                on_visibility_change.as_ref().unchecked_ref(),
+            )
+            .unwrap();
+
+        hyphae_utils::wait_ms(50).await;
+        assert!(polls.get() > 0);
+
+        let controller = set_visibility("hidden");
+        assert!(document.hidden());
+        let paused_at = polls.get();
+        hyphae_utils::wait_ms(50).await;
+        assert_eq!(paused_at, polls.get());
+
+        controller.set("visible");
+        assert!(!document.hidden());
+        hyphae_utils::wait_ms(50).await;
+        assert!(polls.get() > paused_at);
+
+        document
+            .remove_event_listener_with_callback(
+                "visibilitychange",
+                on_visibility_change.as_ref().unchecked_ref(),
+            )
+            .unwrap();
+        if let Some(id) = interval.take() {
+            window.clear_interval_with_handle(id);
+        }
+    }
+
     #[wasm_bindgen_test]
     async fn send_str_to_mock_ws() {
         let controller = mock_ws(100);
@@ -0,0 +1,88 @@
+#![warn(missing_docs)]
+//! # hyphae Test Macros
+//!
+//! Attribute macros to reduce the boilerplate of hyphae tests.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, FnArg, ItemFn, Pat};
+
+/// Marks a function as a [`wasm_bindgen_test`](https://crates.io/crates/wasm-bindgen-test) that
+/// runs in the browser.
+///
+/// This removes the need for `wasm_bindgen_test_configure!(run_in_browser)` in the test module.
+///
+/// A test function can have a single argument, which will be a fresh `QueryElement` created with
+/// `QueryElement::new()`. The `QueryElement` is removed from the DOM when it is dropped at the
+/// end of the test.
+///
+/// # Examples
+/// ```ignore
+/// use hyphae::prelude::*;
+/// use hyphae_test_macros::hyphae_test;
+/// use web_sys::HtmlButtonElement;
+///
+/// #[hyphae_test]
+/// fn render_and_query(rendered: QueryElement) {
+///     rendered.set_inner_html("<button>Ok</button>");
+///     let ok: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Ok");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn hyphae_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new(attr.span(), "hyphae_test does not accept any arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let mut function = parse_macro_input!(item as ItemFn);
+
+    let rendered = match function.sig.inputs.len() {
+        0 => None,
+        1 => match function.sig.inputs.first() {
+            Some(FnArg::Typed(arg)) => match arg.pat.as_ref() {
+                Pat::Ident(ident) => Some((ident.clone(), arg.ty.clone())),
+                pat => {
+                    return syn::Error::new(pat.span(), "expected an identifier")
+                        .to_compile_error()
+                        .into()
+                }
+            },
+            _ => {
+                return syn::Error::new(function.sig.inputs.span(), "self is not supported")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        _ => {
+            return syn::Error::new(
+                function.sig.inputs.span(),
+                "hyphae_test only accepts a single QueryElement argument",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    function.sig.inputs.clear();
+
+    if let Some((ident, ty)) = rendered {
+        let block = &function.block;
+        function.block = syn::parse_quote!({
+            let #ident: #ty = hyphae::QueryElement::new();
+            #block
+        });
+    }
+
+    (quote! {
+        // scoped to avoid the configuration colliding with other tests in the module
+        const _: () = {
+            wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+        };
+
+        #[wasm_bindgen_test::wasm_bindgen_test]
+        #function
+    })
+    .into()
+}
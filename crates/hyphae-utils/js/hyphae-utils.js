@@ -34,6 +34,16 @@ export function wait_promise(ms) {
 	})
 }
 
+export function flush_promise() {
+	return new Promise((resolve) => {
+		// updates scheduled for the next frame run before the rAF callback, then the microtasks
+		// queued by those updates run before the Promise resolves
+		requestAnimationFrame(() => {
+			setTimeout(resolve, 0);
+		});
+	});
+}
+
 export function until_mutation(element, action, timeout) {
 	return new Promise((resolve, reject) => {
 		const observerOptions = {
@@ -55,3 +65,43 @@ export function until_mutation(element, action, timeout) {
 		}
 	});
 }
+
+export function until_condition(element, action, condition, timeout) {
+	return new Promise((resolve, reject) => {
+		const observerOptions = {
+			childList: true,
+			attributes: true,
+			subtree: true,
+			characterData: true,
+		};
+
+		let wait;
+		const finish = () => {
+			clearTimeout(wait);
+			observer.disconnect();
+		};
+
+		const observer = new MutationObserver(() => {
+			if (condition()) {
+				finish();
+				resolve();
+			}
+		});
+		observer.observe(element, observerOptions);
+		action()
+		if (condition()) {
+			finish();
+			resolve();
+		} else if (timeout) {
+			wait = setTimeout(() => {
+				finish();
+				reject(`Condition not met within the allotted time: ${timeout}ms.`);
+			}, timeout)
+		}
+	});
+}
+
+export function set_native_input_value(element, value) {
+	const { set } = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, 'value');
+	set.call(element, value);
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use js_sys::Function;
+use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen_futures::JsFuture;
+
+#[wasm_bindgen(module = "/js/hyphae-utils.js")]
+extern "C" {
+    fn wait_promise(ms: JsValue) -> js_sys::Promise;
+    fn flush_promise() -> js_sys::Promise;
+    fn until_mutation(element: &JsValue, action: &Function, timeout: JsValue) -> js_sys::Promise;
+    fn until_condition(
+        element: &JsValue,
+        action: &Function,
+        condition: &Function,
+        timeout: JsValue,
+    ) -> js_sys::Promise;
+    #[wasm_bindgen(js_name = set_native_input_value)]
+    fn set_native_input_value_js(element: &JsValue, value: &str);
+}
+
+/// Set the value of an input element using the native `HTMLInputElement.prototype.value` setter.
+///
+/// This bypasses any `value` property defined on the element instance itself, which some
+/// frameworks use to track the value of controlled inputs.
+pub fn set_native_input_value<T: JsCast>(element: &T, value: &str) {
+    set_native_input_value_js(element.unchecked_ref(), value);
+}
+
+/// Perform an action and await a DOM change with a timeout duration.
+///
+/// This function uses the MutationObserver in JS to track whether a change in the DOM has occurred
+/// for the element given or it's subtree, this includes attribute changes.
+///
+/// The Future will wait until the allotted time for a change in the DOM
+/// to occur. If no DOM change occurs then this function will panic.
+pub async fn effect_dom<F>(element: &JsValue, action: F, timeout: Duration)
+where
+    F: Fn() + 'static,
+{
+    let timeout = timeout.as_millis().into();
+    let function = Closure::wrap(Box::new(action) as Box<dyn Fn()>);
+    JsFuture::from(until_mutation(
+        element,
+        function.as_ref().unchecked_ref(),
+        timeout,
+    ))
+    .await
+    .unwrap_throw();
+}
+
+/// Perform an action and await a DOM change that meets the condition given, with a timeout
+/// duration.
+///
+/// Unlike [`effect_dom`] the Future doesn't complete on the first change in the DOM, the
+/// `condition` is checked after the action and after every change to the element or its subtree
+/// and the Future completes once the `condition` returns `true`.
+///
+/// # Errors
+/// If the `condition` is not met within the allotted time.
+pub async fn effect_dom_until<F, C>(
+    element: &JsValue,
+    action: F,
+    condition: C,
+    timeout: Duration,
+) -> Result<(), JsValue>
+where
+    F: Fn() + 'static,
+    C: Fn() -> bool + 'static,
+{
+    let timeout = timeout.as_millis().into();
+    let action = Closure::wrap(Box::new(action) as Box<dyn Fn()>);
+    let condition = Closure::wrap(Box::new(condition) as Box<dyn Fn() -> bool>);
+    JsFuture::from(until_condition(
+        element,
+        action.as_ref().unchecked_ref(),
+        condition.as_ref().unchecked_ref(),
+        timeout,
+    ))
+    .await
+    .map(|_| ())
+}
+
+/// Asynchronous wait for a given amount of ms.
+///
+/// This is a Rust Future which uses an underlying JS Promise and Timeout.
+/// This can be useful to assert something has occurred, or not, after a given amount of time -
+/// especially as you cannot use [sleep](std::thread::sleep) in a test using
+/// [`wasm_bindgen_test`](wasm_bindgen_testhttps://crates.io/crates/wasm-bindgen-test/).
+///
+/// # Examples
+/// ```no_run
+///
+/// use wasm_bindgen_test::*;
+///
+/// #[wasm_bindgen_test]
+/// async fn some_test_that_requires_waiting() {
+///     // setup..
+///     // wait 500ms
+///     hyphae_utils::wait_ms(500);
+///     // some asserts..
+/// }
+/// ```
+pub async fn wait_ms(ms: u32) {
+    JsFuture::from(wait_promise(ms.into())).await.unwrap_throw();
+}
+
+/// Asynchronous wait for the updates scheduled by a framework to be applied to the DOM.
+///
+/// Frameworks often schedule a render in a microtask or an animation frame rather than updating
+/// the DOM straight away. This awaits the next animation frame and then a task, so that any
+/// microtasks queued by the frame have also run.
+pub async fn flush_updates() {
+    JsFuture::from(flush_promise()).await.unwrap_throw();
+}
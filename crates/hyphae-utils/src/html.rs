@@ -34,6 +34,17 @@ get_js_property_impl! {
     get_element_value, set_element_value, map_element_value, "value":String
 }
 
+/// Returns the classes of an element, in the order they appear in the `class` attribute.
+///
+/// An element without a `class` attribute will return an empty [`Vec`].
+pub fn class_list<T: JsCast>(element: &T) -> Vec<String> {
+    element
+        .unchecked_ref::<Element>()
+        .get_attribute("class")
+        .map(|classes| classes.split_whitespace().map(str::to_owned).collect())
+        .unwrap_or_default()
+}
+
 pub fn format_html(html: &str) -> String {
     format(html.into()).as_string().unwrap()
 }
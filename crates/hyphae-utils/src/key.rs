@@ -1,6 +1,6 @@
 ///! Key
 
-/// A newtype around a [`Vec<Key>`] for use with [`type_to!`] macro.
+/// A newtype around a [`Vec<Key>`] for use with the `type_to!` macro of `hyphae`.
 pub struct Keys(Vec<Key>);
 
 impl std::ops::Deref for Keys {
@@ -42,7 +42,8 @@ impl From<Vec<Key>> for Keys {
     }
 }
 
-/// An enum for the possible event types for [`web_sys::KeyboardEvent`]s.
+/// An enum for the possible event types for
+/// [`KeyboardEvent`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent)s.
 #[derive(Clone, Copy)]
 pub enum KeyEventType {
     /// The `keydown` event type.
@@ -118,7 +119,8 @@ macro_rules! key_impl {
 
 key_impl! {
     /**
-    Standard key value to be used to represent the [`web_sys::KeyboardEvent::key()`].
+    Standard key value to be used to represent the
+    [`KeyboardEvent.key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key).
     The [list of keys](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key/Key_Values)
     used can be found on MDN.
     */
@@ -471,3 +473,44 @@ key_impl! {
         Separator,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_display_is_key_value() {
+        assert_eq!("a", Key::Lit('a').to_string());
+        assert_eq!("🎉", Key::Lit('🎉').to_string());
+        assert_eq!("Enter", Key::Enter.to_string());
+        assert_eq!("ArrowDown", Key::ArrowDown.to_string());
+    }
+
+    #[test]
+    fn only_literal_keys_are_visible() {
+        assert!(Key::Lit(' ').is_visible());
+        assert!(!Key::Backspace.is_visible());
+    }
+
+    #[test]
+    fn key_event_type_names() {
+        let names: Vec<&str> = [
+            KeyEventType::KeyDown,
+            KeyEventType::KeyPress,
+            KeyEventType::KeyUp,
+        ]
+        .iter()
+        .map(|&event_type| event_type.into())
+        .collect();
+        assert_eq!(vec!["keydown", "keypress", "keyup"], names);
+    }
+
+    #[test]
+    fn keys_from_str_are_literal() {
+        let keys: Keys = "hi!".into();
+        assert_eq!(
+            vec!["h", "i", "!"],
+            keys.iter().map(Key::to_string).collect::<Vec<_>>()
+        );
+    }
+}
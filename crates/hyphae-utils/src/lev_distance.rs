@@ -32,17 +32,84 @@ pub(crate) fn lev_distance(me: &str, t: &str) -> usize {
     dcol[t_last + 1]
 }
 
+/// The largest [Levenshtein distance](https://en.wikipedia.org/wiki/Levenshtein_distance)
+/// between two strings for them to be considered close, which is enough for typical typos.
+const MAX_CLOSE_DISTANCE: usize = 3;
+
+/// Whether the strings are close, within a few single character edits (insertions, deletions
+/// or substitutions) of each other.
+///
+/// This is used by the queries to suggest an element when there is no exact match and is
+/// useful for approximate assertions.
+///
+/// # Examples
+/// ```
+/// use hyphae_utils::is_close;
+///
+/// assert!(is_close("Click me", "Click me!"));
+/// assert!(!is_close("Click me", "Submit"));
+/// ```
 pub fn is_close(a: &str, b: &str) -> bool {
-    lev_distance(a, b) < 4
+    lev_distance(a, b) <= MAX_CLOSE_DISTANCE
+}
+
+/// Same as [`is_close`], as a name that reads better in an assertion.
+///
+/// # Examples
+/// ```
+/// use hyphae_utils::fuzzy_eq;
+///
+/// assert!(fuzzy_eq("Password is too short", "Password is to short"));
+/// ```
+pub fn fuzzy_eq(a: &str, b: &str) -> bool {
+    is_close(a, b)
+}
+
+/// Returns the closest candidate to the target, if any candidate is close (see [`is_close`]).
+///
+/// When more than one candidate is the same distance from the target, the first is returned.
+///
+/// # Examples
+/// ```
+/// use hyphae_utils::suggest;
+///
+/// let buttons = ["Save", "Cancel", "Delete"];
+/// assert_eq!(Some("Cancel"), suggest("Cancle", buttons.iter().copied()));
+/// assert_eq!(None, suggest("Submit", buttons.iter().copied()));
+/// ```
+pub fn suggest<'a, I>(target: &str, candidates: I) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    candidates
+        .into_iter()
+        .map(|candidate| (lev_distance(target, candidate), candidate))
+        .filter(|&(d, _)| d <= MAX_CLOSE_DISTANCE)
+        .min_by_key(|t| t.0)
+        .map(|t| t.1)
 }
 
+/// Returns the closest item to the `search`, if any item is close (see [`is_close`]).
+///
+/// The `to_key` function returns the string of the item to compare with the `search`, such as
+/// the text of an element. When more than one item is the same distance from the `search`, the
+/// first is returned.
+///
+/// # Examples
+/// ```
+/// use hyphae_utils::closest;
+///
+/// let names = vec![(1, "Alice".to_owned()), (2, "Bob".to_owned())];
+/// let (id, _) = closest("Alcie", names.into_iter(), |(_, name)| name).unwrap();
+/// assert_eq!(1, id);
+/// ```
 pub fn closest<T, I, F>(search: &str, iter: I, to_key: F) -> Option<T>
 where
     I: Iterator<Item = T>,
     F: Fn(&T) -> &String,
 {
     iter.map(|e| (lev_distance(search, to_key(&e)), e))
-        .filter(|&(d, _)| d < 4)
+        .filter(|&(d, _)| d <= MAX_CLOSE_DISTANCE)
         .min_by_key(|t| t.0)
         .map(|t| t.1)
 }
@@ -61,4 +128,37 @@ mod tests {
         closest("Clik Me", [element_text_content].iter(), |s| s)
             .expect("'Clik Me' to find 'Click Me!' as a recommendation");
     }
+
+    #[test]
+    fn fuzzy_eq_allows_small_typos() {
+        assert!(fuzzy_eq("Password is too short", "Password is to short"));
+        assert!(fuzzy_eq("Saved", "Saved!"));
+        assert!(!fuzzy_eq("Saved", "Deleted"));
+    }
+
+    #[test]
+    fn suggest_closest_from_list() {
+        let candidates = ["Sign in", "Sign up", "Sign out"];
+
+        assert_eq!(
+            Some("Sign out"),
+            suggest("Sign ou", candidates.iter().copied())
+        );
+        // the first candidate is returned when they are the same distance
+        assert_eq!(Some("Sign in"), suggest("Sign", candidates.iter().copied()));
+        assert_eq!(None, suggest("Register", candidates.iter().copied()));
+        assert_eq!(None, suggest("Sign in", std::iter::empty()));
+    }
+
+    #[test]
+    fn levenshtein_distances() {
+        assert_eq!(0, lev_distance("Save", "Save"));
+        assert_eq!(4, lev_distance("", "Save"));
+        assert_eq!(4, lev_distance("Save", ""));
+        // substitution, insertion and deletion
+        assert_eq!(1, lev_distance("Save", "Sane"));
+        assert_eq!(1, lev_distance("Save", "Saves"));
+        assert_eq!(1, lev_distance("Save", "Sae"));
+        assert_eq!(3, lev_distance("kitten", "sitting"));
+    }
 }
@@ -224,7 +224,7 @@ impl Model {
                         onclick={link.callback(move |_| Msg::Toggle(idx))}
                     />
                     <label for={check_id} ondblclick={link.callback(move |_| Msg::ToggleEdit(idx))}>{ &entry.description }</label>
-                    <button aria-controls={id} class="destroy" onclick={link.callback(move |_| Msg::Remove(idx))} />
+                    <button aria-controls={id} aria-label="Delete" class="destroy" onclick={link.callback(move |_| Msg::Remove(idx))} />
                 </div>
                 { self.view_entry_edit_input(link, (idx, entry)) }
             </li>
@@ -455,4 +455,32 @@ mod tests {
 
         clear_completed_btn.click();
     }
+
+    #[wasm_bindgen_test]
+    fn every_interactive_element_has_an_accessible_name() {
+        let rendered = QueryElement::default();
+        let _ = yew::start_app_in_element::<Model>(rendered.clone().into());
+
+        // add a todo so the controls of a todo item are audited too
+        let input: HtmlInputElement = rendered.assert_by_placeholder_text("What needs to be done?");
+        type_to!(input, "Audit", Key::Enter);
+
+        let elements = rendered.interactive_elements();
+        assert!(elements
+            .iter()
+            .any(|(role, name, _)| *role == AriaRole::Button && name == "Delete"));
+        for (role, name, element) in elements {
+            assert!(
+                !name.is_empty(),
+                "{:?} has no accessible name: {}",
+                role,
+                element.outer_html()
+            );
+        }
+
+        // time to clean up!
+        rendered
+            .assert_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Delete")
+            .click();
+    }
 }
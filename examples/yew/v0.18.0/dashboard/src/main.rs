@@ -260,8 +260,10 @@ mod tests {
 
         // We need to wait for a bit here because fetch is async
         // even with a Promise that resolves immediately it will be delayed
-        // Use effect_dom to add a future that won't complete until the dom changes or gets timed out.
-        hyphae::utils::effect_dom(&rendered, move || button.click(), Duration::ZERO).await;
+        // Use effect to add a future that won't complete until the dom changes or gets timed out.
+        rendered
+            .effect(move || button.click(), Duration::ZERO)
+            .await;
 
         // check that mock value has been added to the DOM.
         rendered.assert_by_text::<HtmlElement>("20");
@@ -278,7 +280,9 @@ mod tests {
         let button = rendered
             .assert_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Fetch Data [binary]");
 
-        hyphae::utils::effect_dom(&rendered, move || button.click(), Duration::ZERO).await;
+        rendered
+            .effect(move || button.click(), Duration::ZERO)
+            .await;
 
         rendered.assert_by_text::<HtmlElement>("50");
     }
@@ -294,7 +298,9 @@ mod tests {
         let button = rendered
             .assert_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Fetch Data [toml]");
 
-        hyphae::utils::effect_dom(&rendered, move || button.click(), Duration::ZERO).await;
+        rendered
+            .effect(move || button.click(), Duration::ZERO)
+            .await;
 
         rendered.assert_by_text::<HtmlElement>("230");
     }
@@ -0,0 +1,139 @@
+//! Read `aria-*` attributes as typed values.
+//!
+//! Attributes are always read as strings, which makes asserting on ARIA attributes verbose:
+//! ```no_run
+//! # use web_sys::Element;
+//! # fn verbose(element: Element) {
+//! assert!(element.get_attribute("aria-expanded").unwrap() == "true");
+//! # }
+//! ```
+//! The [`AriaAttributes`] trait parses the value into the Rust type for the attribute:
+//! ```no_run
+//! # use hyphae::aria::AriaAttributes;
+//! # use web_sys::Element;
+//! # fn typed(element: Element) {
+//! assert_eq!(Some(true), element.aria_bool("expanded"));
+//! # }
+//! ```
+use web_sys::Element;
+
+/// Enables reading `aria-*` attributes of an [`Element`] as typed values.
+///
+/// The `name` of the attribute is given without the `aria-` prefix, so `"expanded"` reads the
+/// `aria-expanded` attribute.
+pub trait AriaAttributes {
+    /// Reads a true/false ARIA attribute, such as `aria-expanded`.
+    ///
+    /// Returns [`None`] when the attribute is missing or the value is not `"true"` or `"false"`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::aria::AriaAttributes;
+    /// use web_sys::Element;
+    ///
+    /// # fn aria_bool_example(button: Element) {
+    /// let button: Element = // get button with aria-expanded="false"
+    ///     # button;
+    /// assert_eq!(Some(false), button.aria_bool("expanded"));
+    /// # }
+    /// ```
+    fn aria_bool(&self, name: &str) -> Option<bool>;
+
+    /// Reads a token ARIA attribute, such as `aria-live`.
+    ///
+    /// Returns [`None`] when the attribute is missing or empty.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::aria::AriaAttributes;
+    /// use web_sys::Element;
+    ///
+    /// # fn aria_token_example(status: Element) {
+    /// let status: Element = // get element with aria-live="polite"
+    ///     # status;
+    /// assert_eq!(Some("polite".to_owned()), status.aria_token("live"));
+    /// # }
+    /// ```
+    fn aria_token(&self, name: &str) -> Option<String>;
+
+    /// Reads an ID reference list ARIA attribute, such as `aria-controls`.
+    ///
+    /// Returns an empty [`Vec`] when the attribute is missing.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::aria::AriaAttributes;
+    /// use web_sys::Element;
+    ///
+    /// # fn aria_idrefs_example(tab: Element) {
+    /// let tab: Element = // get element with aria-controls="panel-1 panel-2"
+    ///     # tab;
+    /// assert_eq!(vec!["panel-1", "panel-2"], tab.aria_idrefs("controls"));
+    /// # }
+    /// ```
+    fn aria_idrefs(&self, name: &str) -> Vec<String>;
+}
+
+impl AriaAttributes for Element {
+    fn aria_bool(&self, name: &str) -> Option<bool> {
+        match self.aria_token(name)?.as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn aria_token(&self, name: &str) -> Option<String> {
+        self.get_attribute(&format!("aria-{}", name))
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn aria_idrefs(&self, name: &str) -> Vec<String> {
+        self.get_attribute(&format!("aria-{}", name))
+            .map(|value| value.split_whitespace().map(str::to_owned).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae::prelude::*;
+    use hyphae_utils::make_element_with_html_string;
+
+    use web_sys::HtmlElement;
+
+    #[wasm_bindgen_test]
+    fn read_aria_attributes_as_typed_values() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button id="menu-button" aria-expanded="true" aria-controls="menu  submenu">
+                Menu
+            </button>
+            <div id="status" role="status" aria-live="polite"></div>
+        "#,
+        )
+        .into();
+
+        let button: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Menu");
+        assert_eq!(Some(true), button.aria_bool("expanded"));
+        assert_eq!(
+            vec!["menu".to_owned(), "submenu".to_owned()],
+            button.aria_idrefs("controls")
+        );
+        assert_eq!(None, button.aria_bool("pressed"));
+
+        let status: HtmlElement = rendered.assert_first_by_selector("#status");
+        assert_eq!(Some("polite".to_owned()), status.aria_token("live"));
+        assert_eq!(None, status.aria_bool("live"));
+        assert!(status.aria_idrefs("controls").is_empty());
+
+        button.set_attribute("aria-expanded", "false").unwrap();
+        assert_eq!(Some(false), button.aria_bool("expanded"));
+    }
+}
@@ -1,5 +1,9 @@
 /// Asserts that a [`Node`](https://rustwasm.github.io/wasm-bindgen/api/web_sys/struct.Node.html)'s
-/// text content is equal to the expected String value (using [`PartialEq`](std::cmp::PartialEq)).
+/// text content is equal to the expected value (using [`PartialEq`](std::cmp::PartialEq)).
+///
+/// The expected value can be any type that implements [`Display`](std::fmt::Display), such as a
+/// `&str`, a `char` or a number, and is compared using its formatted string. Leading and trailing
+/// whitespace is trimmed from both the expected value and the text content before comparing.
 ///
 /// If you want to take into account styling then you will want to use [`assert_inner_text`].
 ///
@@ -28,14 +32,14 @@
 macro_rules! assert_text_content {
     ($expected: expr, $element:expr $(,)?) => {
         if let Some(text) = $element.text_content() {
-            assert_eq!($expected.to_string(), text);
+            assert_eq!(format!("{}", $expected).trim(), text.trim());
         } else {
             panic!("Node does not have any text content");
         }
     };
     ($expected: expr, $element:expr, $($arg:tt)+) => {
         if let Some(text) = $element.text_content() {
-            assert_eq!($expected.to_string(), text, $($arg)+);
+            assert_eq!(format!("{}", $expected).trim(), text.trim(), $($arg)+);
         } else {
             panic!($($arg)+);
         }
@@ -44,7 +48,11 @@ macro_rules! assert_text_content {
 }
 
 /// Asserts that a [`HtmlElement`](https://rustwasm.github.io/wasm-bindgen/api/web_sys/struct.HtmlElement.html)'s
-/// inner text is equal to the expected String value (using [`PartialEq`](std::cmp::PartialEq)).
+/// inner text is equal to the expected value (using [`PartialEq`](std::cmp::PartialEq)).
+///
+/// The expected value can be any type that implements [`Display`](std::fmt::Display), such as a
+/// `&str`, a `char` or a number, and is compared using its formatted string. Leading and trailing
+/// whitespace is trimmed from both the expected value and the inner text before comparing.
 ///
 /// If you want to exclude styling then you will want to use [`assert_text_content`].
 ///
@@ -72,10 +80,194 @@ macro_rules! assert_text_content {
 #[macro_export]
 macro_rules! assert_inner_text {
     ($expected: expr, $element:expr $(,$($arg:tt)+)?) => {
-        assert_eq!($expected.to_string(), $element.inner_text() $(, $($arg)+)?);
+        assert_eq!(
+            format!("{}", $expected).trim(),
+            $element.inner_text().trim()
+            $(, $($arg)+)?
+        );
     }
 }
 
+/// Asserts that an [`Element`](https://rustwasm.github.io/wasm-bindgen/api/web_sys/struct.Element.html)
+/// has the expected class in its class list.
+///
+/// If you want to assert that an element does not have a class then use [`assert_not_class`].
+///
+/// # Examples
+/// The element is the first argument and the expected class is the second:
+/// ```no_run
+/// # use hyphae::assert_has_class;
+/// # use web_sys::Element;
+/// # fn test_assert_has_class(element: Element) {
+/// let element: Element = //.. some function to get Element with class="todo completed"
+///     # element;
+/// assert_has_class!(element, "completed");
+/// # }
+/// ```
+/// A second version is available to add a custom panic message when the element doesn't have the
+/// class:
+/// ```no_run
+/// # use hyphae::assert_has_class;
+/// # use web_sys::Element;
+/// # fn test_assert_has_class(element: Element) {
+/// let element: Element = //.. some function to get Element with class="todo"
+///     # element;
+/// assert_has_class!(element, "completed", "todo item should be completed!");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_has_class {
+    ($element:expr, $class:expr $(,)?) => {{
+        let classes = $crate::utils::class_list(&$element);
+        assert!(
+            classes.iter().any(|class| class == $class),
+            "expected element to have the class '{}' but found: class=\"{}\"",
+            $class,
+            classes.join(" ")
+        );
+    }};
+    ($element:expr, $class:expr, $($arg:tt)+) => {{
+        let classes = $crate::utils::class_list(&$element);
+        assert!(classes.iter().any(|class| class == $class), $($arg)+);
+    }};
+}
+
+/// Asserts that an [`Element`](https://rustwasm.github.io/wasm-bindgen/api/web_sys/struct.Element.html)
+/// does not have the class in its class list.
+///
+/// If you want to assert that an element has a class then use [`assert_has_class`].
+///
+/// # Examples
+/// The element is the first argument and the unexpected class is the second:
+/// ```no_run
+/// # use hyphae::assert_not_class;
+/// # use web_sys::Element;
+/// # fn test_assert_not_class(element: Element) {
+/// let element: Element = //.. some function to get Element with class="todo"
+///     # element;
+/// assert_not_class!(element, "editing");
+/// # }
+/// ```
+/// A second version is available to add a custom panic message when the element has the class:
+/// ```no_run
+/// # use hyphae::assert_not_class;
+/// # use web_sys::Element;
+/// # fn test_assert_not_class(element: Element) {
+/// let element: Element = //.. some function to get Element with class="todo editing"
+///     # element;
+/// assert_not_class!(element, "editing", "todo item should not be editable!");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_not_class {
+    ($element:expr, $class:expr $(,)?) => {{
+        let classes = $crate::utils::class_list(&$element);
+        assert!(
+            !classes.iter().any(|class| class == $class),
+            "expected element to not have the class '{}' but found: class=\"{}\"",
+            $class,
+            classes.join(" ")
+        );
+    }};
+    ($element:expr, $class:expr, $($arg:tt)+) => {{
+        let classes = $crate::utils::class_list(&$element);
+        assert!(!classes.iter().any(|class| class == $class), $($arg)+);
+    }};
+}
+
+/// Asserts that an iterator of elements, such as an [`ElementIter`](crate::ElementIter), has the
+/// expected number of elements.
+///
+/// On failure the panic message has the actual count and the formatted HTML of the rendered
+/// [`QueryElement`](crate::QueryElement), using
+/// [`pretty_panic`](crate::QueryElement::pretty_panic).
+///
+/// The rendered element must be passed as well as the elements, as an
+/// [`ElementIter`](crate::ElementIter) doesn't keep a reference to the root element it was
+/// queried from, so the HTML to show on failure can't be reached from the elements alone.
+///
+/// # Examples
+/// The expected count is the first argument, the elements are the second and the rendered
+/// element is the third:
+/// ```no_run
+/// # use hyphae::prelude::*;
+/// # use web_sys::HtmlElement;
+/// # fn test_assert_count(rendered: QueryElement) {
+/// let rendered: QueryElement = //.. some function to render the 5 todo items
+///     # rendered;
+/// assert_count!(5, rendered.assert_all_by_selector::<HtmlElement>("li"), rendered);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_count {
+    ($expected:expr, $elements:expr, $rendered:expr $(,)?) => {{
+        let expected = $expected;
+        let count = $elements.into_iter().count();
+        if count != expected {
+            $rendered.pretty_panic(&format!(
+                "expected {} elements but found {}",
+                expected, count
+            ));
+        }
+    }};
+}
+
+/// Asserts that an [`Element`](https://rustwasm.github.io/wasm-bindgen/api/web_sys/struct.Element.html)
+/// has the expected `aria-valuetext`, the human readable value of a range widget such as a slider.
+///
+/// # Examples
+/// The expected value text is the first argument, which can be any `AsRef<str>` such as a
+/// `&str` or a `String`, and the element is the second:
+/// ```no_run
+/// # use hyphae::assert_valuetext;
+/// # use web_sys::Element;
+/// # fn test_assert_valuetext(slider: Element) {
+/// let slider: Element = //.. some function to get the volume slider
+///     # slider;
+/// assert_valuetext!("50%", slider);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_valuetext {
+    ($expected:expr, $element:expr $(,)?) => {{
+        let expected = $expected;
+        let expected: &str = ::std::convert::AsRef::as_ref(&expected);
+        let value_text = $element.get_attribute("aria-valuetext");
+        assert!(
+            value_text.as_deref() == Some(expected),
+            "expected element to have aria-valuetext=\"{}\" but found: {:?}",
+            expected,
+            value_text
+        );
+    }};
+}
+
+/// Asserts that a condition is true, otherwise panics with the message and the formatted HTML
+/// of a [`QueryElement`](crate::QueryElement) using
+/// [`pretty_panic`](crate::QueryElement::pretty_panic).
+///
+/// # Examples
+/// The condition is the first argument, the rendered element is the second and the rest is a
+/// format string and arguments, like [`assert`]:
+/// ```no_run
+/// # use hyphae::prelude::*;
+/// # use web_sys::HtmlElement;
+/// # fn test_pretty_assert(rendered: QueryElement) {
+/// let rendered: QueryElement = //.. some function to render the todo list
+///     # rendered;
+/// let items = rendered.assert_all_by_selector::<HtmlElement>("li").count();
+/// pretty_assert!(items == 3, rendered, "expected 3 todo items but found {}", items);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pretty_assert {
+    ($cond:expr, $rendered:expr, $($arg:tt)+) => {
+        if !$cond {
+            $rendered.pretty_panic(&format!($($arg)+));
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::*;
@@ -143,6 +335,44 @@ mod tests {
         assert_text_content!("1 item", rendered);
     }
 
+    #[wasm_bindgen_test]
+    fn toggle_class_and_assert_both_ways() {
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<li id="todo" class="todo">Gardening</li>"#).into();
+
+        let todo = rendered.query_selector("#todo").unwrap().unwrap();
+        assert_eq!(vec!["todo".to_owned()], hyphae::utils::class_list(&todo));
+        assert_has_class!(todo, "todo");
+        assert_not_class!(todo, "completed");
+
+        todo.set_class_name("todo completed");
+
+        assert_eq!(
+            vec!["todo".to_owned(), "completed".to_owned()],
+            hyphae::utils::class_list(&todo)
+        );
+        assert_has_class!(todo, "completed");
+        assert_not_class!(todo, "editing", "todo should not be editable");
+
+        // the asserts can be used where an expression is expected
+        match todo.id().as_str() {
+            "todo" => assert_has_class!(todo, "completed"),
+            _ => assert_not_class!(todo, "completed"),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(
+        expected = "expected element to have the class 'completed' but found: class=\"todo\""
+    )]
+    fn assert_has_class_shows_class_attribute() {
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<li id="todo" class="todo">Gardening</li>"#).into();
+
+        let todo = rendered.query_selector("#todo").unwrap().unwrap();
+        assert_has_class!(todo, "completed");
+    }
+
     #[wasm_bindgen_test]
     fn assert_div_has_text_content() {
         let render = QueryElement::new();
@@ -161,4 +391,78 @@ mod tests {
         let result = render.query_selector("#mydiv").unwrap().unwrap();
         assert_text_content!("text content is broken up!", result);
     }
+
+    #[wasm_bindgen_test]
+    fn assert_text_with_display_values() {
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<span id="count"> 42 </span>"#).into();
+        let count: HtmlElement = rendered.assert_first_by_selector("#count");
+
+        assert_text_content!(42_i64, count);
+        assert_inner_text!(42_i64, count);
+        assert_text_content!("42", count);
+        assert_inner_text!("42", count);
+
+        count.set_text_content(Some("x"));
+        assert_text_content!('x', count);
+        assert_inner_text!('x', count);
+    }
+
+    #[wasm_bindgen_test]
+    fn assert_count_of_all_list_items() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li>Gardening</li>
+                <li>Cooking</li>
+                <li>Cleaning</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        assert_count!(
+            3,
+            rendered.assert_all_by_selector::<HtmlElement>("li"),
+            rendered
+        );
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(expected = "expected 2 elements but found 3\n\nIn the following HTML:")]
+    fn assert_count_shows_actual_count() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li>Gardening</li>
+                <li>Cooking</li>
+                <li>Cleaning</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        assert_count!(
+            2,
+            rendered.assert_all_by_selector::<HtmlElement>("li"),
+            rendered
+        );
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(expected = "expected 3 items but found 2\n\nIn the following HTML:")]
+    fn pretty_assert_shows_message_and_html() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li>Gardening</li>
+                <li>Cooking</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let items = rendered.assert_all_by_selector::<HtmlElement>("li").count();
+        pretty_assert!(items == 3, rendered, "expected 3 items but found {}", items);
+    }
 }
@@ -0,0 +1,88 @@
+//! Print the rendered HTML and its accessibility tree while writing a test.
+//!
+//! [`QueryElement::debug`](crate::QueryElement::debug) and
+//! [`QueryElement::debug_aria`](crate::QueryElement::debug_aria) write to the browser console by
+//! default, which isn't shown by some CI setups. Use [`set_debug_sink`] to send the output
+//! somewhere else, such as the test output.
+//!
+//! Only the output of `debug` and `debug_aria` goes to the sink. The errors of the queries and
+//! [`pretty_panic`](crate::QueryElement::pretty_panic) are deliberately left out: their formatted
+//! HTML is part of the error or panic message, which the test runner already reports whatever
+//! the setup, so sending it to the sink as well would only duplicate the output.
+use std::cell::RefCell;
+
+thread_local! {
+    static DEBUG_SINK: RefCell<Option<Box<dyn Fn(&str)>>> = RefCell::new(None);
+}
+
+/// Sends the debug output of hyphae to the `sink` given instead of `console.log`.
+///
+/// The sink replaces any sink set before, use [`reset_debug_sink`] to go back to `console.log`.
+///
+/// # Examples
+/// ```no_run
+/// use hyphae::{debug::set_debug_sink, prelude::*};
+///
+/// set_debug_sink(Box::new(|output| web_sys::console::error_1(&output.into())));
+/// let rendered = QueryElement::new();
+/// // written with `console.error`
+/// rendered.debug();
+/// ```
+pub fn set_debug_sink(sink: Box<dyn Fn(&str)>) {
+    DEBUG_SINK.with(|debug_sink| *debug_sink.borrow_mut() = Some(sink));
+}
+
+/// Sends the debug output of hyphae to `console.log` again, removing the sink set with
+/// [`set_debug_sink`].
+pub fn reset_debug_sink() {
+    DEBUG_SINK.with(|debug_sink| *debug_sink.borrow_mut() = None);
+}
+
+/// Writes the output to the debug sink, or `console.log` when there is no sink.
+pub(crate) fn write(output: &str) {
+    DEBUG_SINK.with(|debug_sink| match debug_sink.borrow().as_ref() {
+        Some(sink) => sink(output),
+        None => web_sys::console::log_1(&output.into()),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    use super::*;
+    use hyphae::prelude::*;
+
+    thread_local! {
+        static CAPTURED: RefCell<Vec<String>> = RefCell::new(vec![]);
+    }
+
+    /// Resets the debug sink when dropped, so a failing test doesn't leak its sink.
+    struct ResetSink;
+
+    impl Drop for ResetSink {
+        fn drop(&mut self) {
+            reset_debug_sink();
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn capture_debug_output_with_sink() {
+        let _reset = ResetSink;
+        set_debug_sink(Box::new(|output| {
+            CAPTURED.with(|captured| captured.borrow_mut().push(output.to_owned()))
+        }));
+
+        let rendered = QueryElement::render_html(r#"<button>Save</button>"#);
+        rendered.debug();
+        rendered.debug_aria();
+
+        CAPTURED.with(|captured| {
+            let captured = captured.borrow();
+            assert_eq!(2, captured.len());
+            assert!(captured[0].contains("<button>Save</button>"));
+            assert!(captured[1].contains("Button \"Save\": <button>Save</button>"));
+        });
+    }
+}
@@ -2,13 +2,19 @@
 //!
 //! The goal of this module is to remove the boilerplate from firing [`web_sys`] events by providing
 //! helper functions and traits for medium/high level actions.
-mod key;
 
-pub use key::*;
+pub use hyphae_utils::key::*;
 
+use std::time::Duration;
+
+use unicode_segmentation::UnicodeSegmentation;
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
-    Event, EventInit, EventTarget, InputEvent, InputEventInit, KeyboardEvent, KeyboardEventInit,
-    MouseEvent, MouseEventInit,
+    AnimationEvent, AnimationEventInit, Element, Event, EventInit, EventTarget, FocusEvent,
+    FocusEventInit, HashChangeEvent, HashChangeEventInit, HtmlElement, HtmlImageElement,
+    HtmlInputElement, HtmlTextAreaElement, InputEvent, InputEventInit, KeyboardEvent,
+    KeyboardEventInit, MouseEvent, MouseEventInit, Node, SelectionMode, TransitionEvent,
+    TransitionEventInit,
 };
 
 /// Dispatches a single [`KeyboardEvent`] with the type and key provided to the event target.
@@ -65,7 +71,11 @@ where
 /// - `keydown` [`KeyboardEvent`]
 /// - `keypress` [`KeyboardEvent`]
 /// - `keyup` [`KeyboardEvent`]
-/// - `input` [`InputEvent`]
+/// - `input` [`InputEvent`] if the key is visible, or is `Backspace` or `Delete`
+///
+/// A visible key is inserted at the caret of an `input` or `textarea`, replacing any selected
+/// text, and `Backspace` or `Delete` removes the selected text or the character before or after
+/// the caret.
 ///
 /// # Examples
 /// ```
@@ -86,13 +96,37 @@ where
 {
     let key = key.into();
     type_key_only(element, key);
-    if key.is_visible() {
-        let mut init = InputEventInit::new();
-        init.data(Some(&key.to_string()));
-        init.bubbles(true);
-        init.input_type("insertText");
-        dispatch_input_event(element, init);
-    }
+    input_for_key(element, key);
+}
+
+/// Presses a single key and then inputs it to the [`EventTarget`].
+///
+/// This fires the same events, in the same order, as [`type_key`]:
+/// - `keydown` [`KeyboardEvent`]
+/// - `keypress` [`KeyboardEvent`]
+/// - `keyup` [`KeyboardEvent`]
+/// - `input` [`InputEvent`] if the key is visible, or is `Backspace` or `Delete`
+///
+/// Use this for components that listen to both `keypress` and `input` events, where the name
+/// makes it clear that both kinds of event are expected to fire.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::*;
+/// use web_sys::HtmlInputElement;
+///
+/// # fn press_and_input_example(input: HtmlInputElement) {
+/// let input: HtmlInputElement = // some function to get input element;
+///     # input;
+/// press_and_input(&input, 'a');
+/// assert_eq!("a", input.value());
+/// # }
+/// ```
+pub fn press_and_input<K>(element: &EventTarget, key: K)
+where
+    K: Into<Key>,
+{
+    type_key(element, key);
 }
 
 /// A simple simulation of typing a multiple keys to the [`EventTarget`].
@@ -101,7 +135,7 @@ where
 /// - `keydown` [`KeyboardEvent`]
 /// - `keypress` [`KeyboardEvent`]
 /// - `keyup` [`KeyboardEvent`]
-/// - `input` [`InputEvent`] if the key is visible
+/// - `input` [`InputEvent`] if the key is visible, or is `Backspace` or `Delete`
 ///
 /// # Examples
 /// ```
@@ -125,6 +159,144 @@ where
     }
 }
 
+/// Simulates typing multiple keys to the [`EventTarget`], waiting for the DOM to change after each
+/// key before typing the next.
+///
+/// Each key fires the same events as [`type_key`] and then uses
+/// [`effect_dom`](hyphae_utils::effect_dom) to await a change anywhere in the DOM tree that
+/// contains the [`EventTarget`]. This avoids races with components that re-render between
+/// keystrokes, as the component will have processed one key before the next arrives.
+///
+/// # Panics
+/// If a key does not cause a change in the DOM within the `timeout` given.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::*;
+/// use std::time::Duration;
+/// use web_sys::HtmlInputElement;
+///
+/// # async fn type_to_effect_example(input: HtmlInputElement) {
+/// let input: HtmlInputElement = // some function to get input element;
+///     # input;
+/// type_to_effect(&input, "abc", Duration::from_millis(100)).await;
+/// assert_eq!("abc", input.value());
+/// # }
+/// ```
+pub async fn type_to_effect<K>(element: &EventTarget, keys: K, timeout: Duration)
+where
+    K: Into<Keys>,
+{
+    let observed: JsValue = element.unchecked_ref::<Node>().get_root_node().into();
+    let keys = keys.into();
+    for key in keys.iter().copied() {
+        let element = element.clone();
+        hyphae_utils::effect_dom(&observed, move || type_key(&element, key), timeout).await;
+    }
+}
+
+/// Simulates inserting text to the [`EventTarget`] one grapheme cluster at a time.
+///
+/// Unlike [`type_keys`], which works on [`char`]s, this will keep multi-codepoint characters such
+/// as flag emojis (🇬🇧) or family emojis (👨‍👩‍👧) together. Each grapheme cluster fires a single
+/// `input` [`InputEvent`] with the `insertText` input type and the grapheme cluster as the `data`.
+///
+/// No [`KeyboardEvent`]s are fired - a grapheme cluster might not map to a single [`Key`].
+///
+/// # Examples
+/// ```
+/// use hyphae::event::*;
+/// use web_sys::HtmlInputElement;
+///
+/// # fn type_text_example(input: HtmlInputElement) {
+/// let input: HtmlInputElement = // some function to get input element;
+///     # input;
+/// type_text(&input, "Go 🇬🇧!");
+/// assert_eq!("Go 🇬🇧!", input.value());
+/// # }
+/// ```
+pub fn type_text(element: &EventTarget, text: &str) {
+    for grapheme in text.graphemes(true) {
+        let mut init = InputEventInit::new();
+        init.data(Some(grapheme));
+        init.bubbles(true);
+        init.input_type("insertText");
+        dispatch_input_event(element, init);
+    }
+}
+
+/// Simulates a user typing text to the [`EventTarget`], one key at a time.
+///
+/// This will fire the following events, in this order, for each [`Key`]:
+/// - `keydown` [`KeyboardEvent`]
+/// - `keypress` [`KeyboardEvent`]
+/// - `input` [`InputEvent`] if the key is visible, or is `Backspace` or `Delete`
+/// - `keyup` [`KeyboardEvent`]
+///
+/// The value of the element is updated before each `input` event is dispatched, so a listener
+/// will see the accumulating value on every keystroke (`"h"`, `"he"`, `"hel"`...). This works for
+/// `input` and `textarea` elements, which have a `value`, and for `contenteditable` elements,
+/// where the text content is updated instead.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::*;
+/// use web_sys::HtmlTextAreaElement;
+///
+/// # fn simulate_typing_example(textarea: HtmlTextAreaElement) {
+/// let textarea: HtmlTextAreaElement = // some function to get textarea element;
+///     # textarea;
+/// simulate_typing(&textarea, "hello");
+/// assert_eq!("hello", textarea.value());
+/// # }
+/// ```
+pub fn simulate_typing<K>(element: &EventTarget, keys: K)
+where
+    K: Into<Keys>,
+{
+    let keys = keys.into();
+    for key in keys.iter().copied() {
+        dispatch_key_event(element, KeyEventType::KeyDown, key);
+        dispatch_key_event(element, KeyEventType::KeyPress, key);
+        input_for_key(element, key);
+        dispatch_key_event(element, KeyEventType::KeyUp, key);
+    }
+}
+
+/// Dispatches the `input` [`InputEvent`] of a key, if it has one.
+///
+/// A visible key inserts its text and `Backspace` or `Delete` removes the selected text or the
+/// character before or after the caret of an `input` or `textarea`.
+fn input_for_key(element: &EventTarget, key: Key) {
+    let mut init = InputEventInit::new();
+    init.bubbles(true);
+    if key.is_visible() {
+        init.data(Some(&key.to_string()));
+        init.input_type("insertText");
+    } else {
+        let control = match TextControl::new(element) {
+            Some(control) => control,
+            None => return,
+        };
+        let (start, end) = match control.selection() {
+            Some(selection) => selection,
+            None => return,
+        };
+        let (start, end, input_type) = match key {
+            Key::Backspace if start == end => {
+                (start.saturating_sub(1), end, "deleteContentBackward")
+            }
+            Key::Backspace => (start, end, "deleteContentBackward"),
+            Key::Delete if start == end => (start, end + 1, "deleteContentForward"),
+            Key::Delete => (start, end, "deleteContentForward"),
+            _ => return,
+        };
+        control.replace("", start, end.min(control.len()));
+        init.input_type(input_type);
+    }
+    dispatch_input_event(element, init);
+}
+
 fn type_key_only(element: &EventTarget, key: Key) {
     for &key_event_type in [
         KeyEventType::KeyDown,
@@ -239,107 +411,699 @@ pub fn dispatch_input_event(element: &EventTarget, data: InputEventInit) {
     //     hyphae_utils::set_element_value(element, value);
     // }
     if let Some(data) = data.as_ref() {
-        hyphae_utils::map_element_value(element, |mut value| {
+        // the text is inserted at the caret, replacing any selected text, when the element
+        // supports selection otherwise it is appended to the value
+        let selection = TextControl::new(element)
+            .and_then(|control| control.selection().map(|selection| (control, selection)));
+        if let Some((control, (start, end))) = selection {
+            control.replace(data, start, end);
+        } else if !hyphae_utils::map_element_value(element, |mut value| {
             value.push_str(data);
             value
-        });
+        }) {
+            if let Some(editable) = element
+                .dyn_ref::<HtmlElement>()
+                .filter(|el| el.is_content_editable())
+            {
+                let mut text = editable.text_content().unwrap_or_default();
+                text.push_str(data);
+                editable.set_text_content(Some(&text));
+            }
+        }
     }
     assert!(element.dispatch_event(&input_event).unwrap());
 }
 
-/// Enables dispatching a bubbling `change` event from an EventTarget
-pub trait EventTargetChanged {
-    /// Dispatches a change [`Event`] on this [`EventTarget`]
-    ///
-    /// # Examples
-    /// ```
-    /// use hyphae::event::EventTargetChanged;
-    /// use web_sys::HtmlInputElement;
-    ///
-    /// # fn dispatch_input_event_example(input: HtmlInputElement) {
-    /// let input: HtmlInputElement = // function to get input element
-    ///     # input;
-    /// // dispatch "change" event
-    /// input.changed();
-    /// # }
-    /// ```
-    fn changed(&self);
+/// Sets the value of the input using the native value setter and then dispatches an `input`
+/// [`InputEvent`].
+///
+/// Some frameworks track the value of a controlled input by defining a `value` property on the
+/// element itself. Setting the value with [`HtmlInputElement::set_value`] updates the tracked
+/// value too, so the `input` event that follows is ignored as nothing appears to have changed.
+/// Use this function when a controlled input does not pick up a value set in this way - the
+/// native `HTMLInputElement.prototype.value` setter bypasses the tracking so the framework sees
+/// the change.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::set_input_value_native;
+/// use web_sys::HtmlInputElement;
+///
+/// # fn set_input_value_native_example(input: HtmlInputElement) {
+/// let input: HtmlInputElement = // function to get input element
+///     # input;
+/// set_input_value_native(&input, "Hello, World!");
+/// assert_eq!("Hello, World!", input.value());
+/// # }
+/// ```
+pub fn set_input_value_native(input: &HtmlInputElement, value: &str) {
+    hyphae_utils::set_native_input_value(input, value);
+    let mut init = InputEventInit::new();
+    init.bubbles(true);
+    init.input_type("insertText");
+    let input_event = InputEvent::new_with_event_init_dict("input", &init).unwrap();
+    assert!(input.dispatch_event(&input_event).unwrap());
 }
 
-impl EventTargetChanged for EventTarget {
-    fn changed(&self) {
-        let mut event_init = EventInit::new();
-        event_init.bubbles(true);
-        let change_event = Event::new_with_event_init_dict("change", &event_init).unwrap();
-        assert!(self.dispatch_event(&change_event).unwrap());
-    }
+/// Dispatches a bubbling `transitionend` [`TransitionEvent`] for the CSS `property` to the event
+/// target.
+///
+/// CSS transitions don't run in headless tests, so the `transitionend` event is never fired by
+/// the browser. Use this to trigger content that is gated on a transition ending.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::transition_end;
+/// use web_sys::HtmlElement;
+///
+/// # fn transition_end_example(panel: HtmlElement) {
+/// let panel: HtmlElement = // function to get the panel element
+///     # panel;
+/// transition_end(&panel, "opacity");
+/// # }
+/// ```
+pub fn transition_end(element: &EventTarget, property: &str) {
+    let mut init = TransitionEventInit::new();
+    init.bubbles(true);
+    init.property_name(property);
+    let event = TransitionEvent::new_with_event_init_dict("transitionend", &init).unwrap();
+    element.dispatch_event(&event).unwrap();
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Dispatches a bubbling `animationend` [`AnimationEvent`] for the CSS animation `name` to the
+/// event target.
+///
+/// CSS animations don't run in headless tests, so the `animationend` event is never fired by
+/// the browser. Use this to trigger content that is gated on an animation ending.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::animation_end;
+/// use web_sys::HtmlElement;
+///
+/// # fn animation_end_example(toast: HtmlElement) {
+/// let toast: HtmlElement = // function to get the toast element
+///     # toast;
+/// animation_end(&toast, "fade-in");
+/// # }
+/// ```
+pub fn animation_end(element: &EventTarget, name: &str) {
+    let mut init = AnimationEventInit::new();
+    init.bubbles(true);
+    init.animation_name(name);
+    let event = AnimationEvent::new_with_event_init_dict("animationend", &init).unwrap();
+    element.dispatch_event(&event).unwrap();
+}
 
-    use wasm_bindgen_test::*;
-    wasm_bindgen_test_configure!(run_in_browser);
+/// Dispatches the event to the global `window`.
+///
+/// Use this for global listeners that can't be reached by dispatching to an element, such as a
+/// `resize` or `popstate` listener.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::dispatch_on_window;
+/// use web_sys::Event;
+///
+/// # fn dispatch_on_window_example() {
+/// let online = Event::new("online").unwrap();
+/// dispatch_on_window(&online);
+/// # }
+/// ```
+pub fn dispatch_on_window(event: &Event) {
+    web_sys::window()
+        .expect("No global window object")
+        .dispatch_event(event)
+        .unwrap();
+}
 
-    use std::cell::Cell;
+/// Dispatches the event to the global `document`.
+///
+/// Use this for listeners on the document, such as a `keydown` listener for keyboard shortcuts.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::dispatch_on_document;
+/// use web_sys::Event;
+///
+/// # fn dispatch_on_document_example() {
+/// let ready = Event::new("readystatechange").unwrap();
+/// dispatch_on_document(&ready);
+/// # }
+/// ```
+pub fn dispatch_on_document(event: &Event) {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .expect("No global document object")
+        .dispatch_event(event)
+        .unwrap();
+}
 
-    use wasm_bindgen::{prelude::Closure, JsCast};
-    use web_sys::{Document, HtmlElement, HtmlInputElement, KeyboardEvent};
+/// Dispatches a `resize` [`Event`] to the global `window`.
+///
+/// The size of the window doesn't change, this only notifies the `resize` listeners so they can
+/// measure the layout again.
+pub fn resize_window() {
+    dispatch_on_window(&Event::new("resize").unwrap());
+}
 
-    use hyphae::{prelude::*, QueryElement};
-    use hyphae_utils::make_element_with_html_string;
+/// Changes the fragment of the URL to `new_hash` and dispatches a `hashchange`
+/// [`HashChangeEvent`] to the global `window`.
+///
+/// The URL is changed with the History API, which doesn't fire a `hashchange` event itself, so
+/// the event is dispatched straight away rather than when the browser gets around to it. A
+/// leading `#` in `new_hash` is optional.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::hash_change;
+///
+/// # fn hash_change_example() {
+/// // show only the completed todos
+/// hash_change("#/completed");
+/// # }
+/// ```
+pub fn hash_change(new_hash: &str) {
+    let window = web_sys::window().expect("No global window object");
+    let location = window.location();
+    let old_url = location.href().unwrap();
+    let new_hash = new_hash.trim_start_matches('#');
+    window
+        .history()
+        .unwrap()
+        .replace_state_with_url(&JsValue::NULL, "", Some(&format!("#{}", new_hash)))
+        .unwrap();
 
-    macro_rules! wasm_closure {
-        (|_: $t:ty| $expr:expr) => {
-            FunctionClosure(Closure::<dyn Fn($t)>::wrap(Box::new(|_: $t| $expr)))
-        };
-        (move |_: $t:ty| $expr:expr) => {
-            FunctionClosure(Closure::<dyn Fn($t)>::wrap(Box::new(move |_: $t| $expr)))
-        };
-        (| $($v:ident: $t:ty),* | $expr:expr) => {
-            FunctionClosure(Closure::<dyn Fn($($t),*)>::wrap(Box::new(|$($v: $t),*| $expr)))
-        };
-        (move | $($v:ident: $t:ty),* | $expr:expr) => {
-            FunctionClosure(Closure::<dyn Fn($($t),*)>::wrap(Box::new(move |$($v: $t),*| $expr)))
-        };
-    }
+    let mut init = HashChangeEventInit::new();
+    init.old_url(&old_url);
+    init.new_url(&location.href().unwrap());
+    let event = HashChangeEvent::new_with_event_init_dict("hashchange", &init).unwrap();
+    window.dispatch_event(&event).unwrap();
+}
 
-    struct FunctionClosure<T: ?Sized>(Closure<T>);
+/// Moves a list item with the keyboard following the ARIA drag and drop pattern.
+///
+/// The following keys are typed to the item, firing `keydown`, `keypress` and `keyup` for each:
+/// - `Space` to pick up the item
+/// - `ArrowUp` for each step when `steps` is negative or `ArrowDown` when `steps` is positive
+/// - `Space` to drop the item
+///
+/// The item is expected to move within its parent element, so the item element must be moved
+/// rather than replaced by the implementation.
+///
+/// # Panics
+/// - If the item doesn't have `aria-grabbed="true"` after being picked up.
+/// - If the item still has `aria-grabbed="true"` after being dropped.
+/// - If the item hasn't moved by `steps` positions within its parent element.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::keyboard_reorder;
+/// use web_sys::HtmlElement;
+///
+/// # fn keyboard_reorder_example(item: HtmlElement) {
+/// let item: HtmlElement = // function to get the third list item
+///     # item;
+/// // move the item to the top of the list
+/// keyboard_reorder(&item, -2);
+/// # }
+/// ```
+pub fn keyboard_reorder(item: &Element, steps: i32) {
+    let position = element_position(item);
 
-    impl<T: ?Sized> std::ops::Deref for FunctionClosure<T> {
-        type Target = js_sys::Function;
+    type_key_only(item, Key::Lit(' '));
+    assert_eq!(
+        Some("true".to_owned()),
+        item.get_attribute("aria-grabbed"),
+        "The item should be grabbed after pressing Space"
+    );
 
-        fn deref(&self) -> &Self::Target {
-            self.0.as_ref().unchecked_ref()
-        }
+    let arrow = if steps < 0 {
+        Key::ArrowUp
+    } else {
+        Key::ArrowDown
+    };
+    for _ in 0..steps.abs() {
+        type_key_only(item, arrow);
     }
 
-    fn global_document() -> Document {
-        web_sys::window()
-            .expect("No global window object")
-            .document()
-            .expect("No global document object")
-    }
+    type_key_only(item, Key::Lit(' '));
+    assert_ne!(
+        Some("true".to_owned()),
+        item.get_attribute("aria-grabbed"),
+        "The item should not be grabbed after being dropped with Space"
+    );
+    assert_eq!(
+        position as i64 + steps as i64,
+        element_position(item) as i64,
+        "The item should have moved {} positions",
+        steps
+    );
+}
 
-    #[wasm_bindgen_test]
-    fn sim_typing_to_input_and_enter_to_confirm() {
-        // setup
+/// The index of the element within the element children of its parent.
+fn element_position(element: &Element) -> usize {
+    std::iter::successors(element.previous_element_sibling(), |e| {
+        e.previous_element_sibling()
+    })
+    .count()
+}
 
-        let rendered: QueryElement = make_element_with_html_string(
-            r#"
-            <p id="key-value">None</p>
-            <label for="input">input label</label>
-            <input id="input" placeholder="key" type="text" />
-        "#,
-        )
-        .into();
+/// Dispatches a `load` [`Event`] on the image as if the image had finished loading.
+///
+/// This runs the `onload` handlers of the image without fetching the image, which is useful for
+/// testing components that show a placeholder until the image has loaded.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::image_load;
+/// use web_sys::HtmlImageElement;
+///
+/// # fn image_load_example(img: HtmlImageElement) {
+/// let img: HtmlImageElement = // function to get the image element
+///     # img;
+/// image_load(&img);
+/// # }
+/// ```
+pub fn image_load(img: &HtmlImageElement) {
+    let event = Event::new("load").unwrap();
+    img.dispatch_event(&event).unwrap();
+}
 
-        let document = global_document();
+/// Dispatches an `error` [`Event`] on the image as if the image had failed to load.
+///
+/// This runs the `onerror` handlers of the image without a failing fetch, which is useful for
+/// testing the fallback of an image.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::image_error;
+/// use web_sys::HtmlImageElement;
+///
+/// # fn image_error_example(img: HtmlImageElement) {
+/// let img: HtmlImageElement = // function to get the image element
+///     # img;
+/// image_error(&img);
+/// # }
+/// ```
+pub fn image_error(img: &HtmlImageElement) {
+    let event = Event::new("error").unwrap();
+    img.dispatch_event(&event).unwrap();
+}
 
-        let key_value_output = document
-            .get_element_by_id("key-value")
-            .expect("no element with 'key-value' id found")
+/// Dispatches the events fired when the event target gains focus, a `focus` [`FocusEvent`]
+/// followed by a bubbling `focusin` [`FocusEvent`].
+///
+/// The `related_target` is the element that lost focus, if any, which is the `relatedTarget` of
+/// both events. Focus is not actually moved to the event target, use
+/// [`HtmlElement::focus`](web_sys::HtmlElement::focus) for that.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::focus_in;
+/// use web_sys::HtmlElement;
+///
+/// # fn focus_in_example(search: HtmlElement, menu_button: HtmlElement) {
+/// let search: HtmlElement = // function to get the search input
+///     # search;
+/// let menu_button: HtmlElement = // function to get the element focus came from
+///     # menu_button;
+/// focus_in(&search, Some(&menu_button));
+/// # }
+/// ```
+pub fn focus_in(element: &EventTarget, related_target: Option<&EventTarget>) {
+    dispatch_focus_event(element, "focus", false, related_target);
+    dispatch_focus_event(element, "focusin", true, related_target);
+}
+
+/// Dispatches the events fired when the event target loses focus, a `blur` [`FocusEvent`]
+/// followed by a bubbling `focusout` [`FocusEvent`].
+///
+/// The `related_target` is the element that is gaining focus, if any, which is the
+/// `relatedTarget` of both events. This is useful for testing a focus trap, which checks whether
+/// focus is leaving the widget.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::focus_out;
+/// use web_sys::HtmlElement;
+///
+/// # fn focus_out_example(last: HtmlElement, outside: HtmlElement) {
+/// let last: HtmlElement = // function to get the last button in a dialog
+///     # last;
+/// let outside: HtmlElement = // function to get an element outside of the dialog
+///     # outside;
+/// focus_out(&last, Some(&outside));
+/// # }
+/// ```
+pub fn focus_out(element: &EventTarget, related_target: Option<&EventTarget>) {
+    dispatch_focus_event(element, "blur", false, related_target);
+    dispatch_focus_event(element, "focusout", true, related_target);
+}
+
+fn dispatch_focus_event(
+    element: &EventTarget,
+    event_type: &str,
+    bubbles: bool,
+    related_target: Option<&EventTarget>,
+) {
+    let mut init = FocusEventInit::new();
+    init.bubbles(bubbles);
+    init.related_target(related_target);
+    let event = FocusEvent::new_with_focus_event_init_dict(event_type, &init).unwrap();
+    element.dispatch_event(&event).unwrap();
+}
+
+/// Dispatches the events fired when the pointer moves onto the event target, a bubbling
+/// `mouseover` [`MouseEvent`] followed by a `mouseenter` [`MouseEvent`].
+///
+/// The `related_target` is the element the pointer left, if any, which is the `relatedTarget`
+/// of both events.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::mouse_enter;
+/// use web_sys::HtmlElement;
+///
+/// # fn mouse_enter_example(tooltip_trigger: HtmlElement) {
+/// let tooltip_trigger: HtmlElement = // function to get the element with a tooltip
+///     # tooltip_trigger;
+/// mouse_enter(&tooltip_trigger, None);
+/// # }
+/// ```
+pub fn mouse_enter(element: &EventTarget, related_target: Option<&EventTarget>) {
+    dispatch_mouse_event(element, "mouseover", true, related_target);
+    dispatch_mouse_event(element, "mouseenter", false, related_target);
+}
+
+/// Dispatches the events fired when the pointer moves off the event target, a bubbling
+/// `mouseout` [`MouseEvent`] followed by a `mouseleave` [`MouseEvent`].
+///
+/// The `related_target` is the element the pointer moved to, if any, which is the
+/// `relatedTarget` of both events.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::mouse_leave;
+/// use web_sys::HtmlElement;
+///
+/// # fn mouse_leave_example(menu: HtmlElement, submenu: HtmlElement) {
+/// let menu: HtmlElement = // function to get the menu element
+///     # menu;
+/// let submenu: HtmlElement = // function to get the element the pointer moves to
+///     # submenu;
+/// mouse_leave(&menu, Some(&submenu));
+/// # }
+/// ```
+pub fn mouse_leave(element: &EventTarget, related_target: Option<&EventTarget>) {
+    dispatch_mouse_event(element, "mouseout", true, related_target);
+    dispatch_mouse_event(element, "mouseleave", false, related_target);
+}
+
+/// Simulates a full mouse click, with the press and release, on the [`EventTarget`].
+///
+/// This will fire the following bubbling [`MouseEvent`]s, in this order, on the target:
+/// - `mousedown`
+/// - `mouseup`
+/// - `click`
+///
+/// Unlike [`HtmlElement::click`], which only fires `click`, this runs the listeners that track
+/// whether an element is being pressed, such as a button with a pressed style.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::full_click;
+/// use web_sys::HtmlButtonElement;
+///
+/// # fn full_click_example(button: HtmlButtonElement) {
+/// let button: HtmlButtonElement = // function to get the button element
+///     # button;
+/// full_click(&button);
+/// # }
+/// ```
+pub fn full_click(element: &EventTarget) {
+    for event_type in ["mousedown", "mouseup", "click"].iter() {
+        dispatch_mouse_event(element, event_type, true, None);
+    }
+}
+
+fn dispatch_mouse_event(
+    element: &EventTarget,
+    event_type: &str,
+    bubbles: bool,
+    related_target: Option<&EventTarget>,
+) {
+    let mut init = MouseEventInit::new();
+    init.bubbles(bubbles);
+    init.related_target(related_target);
+    let event = MouseEvent::new_with_mouse_event_init_dict(event_type, &init).unwrap();
+    element.dispatch_event(&event).unwrap();
+}
+
+/// Returns the start and end of the selected text of the input, which are equal when nothing is
+/// selected and are the position of the caret.
+///
+/// The positions are in UTF-16 code units, as in JS.
+///
+/// # Panics
+/// If the type of input doesn't support selection, such as `email` or `number`.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::*;
+/// use web_sys::HtmlInputElement;
+///
+/// # fn selection_range_example(input: HtmlInputElement) {
+/// let input: HtmlInputElement = // some function to get input element;
+///     # input;
+/// type_keys(&input, "abc");
+/// assert_eq!((3, 3), selection_range(&input));
+/// # }
+/// ```
+pub fn selection_range(input: &HtmlInputElement) -> (u32, u32) {
+    TextControl::Input(input.clone())
+        .selection()
+        .unwrap_or_else(|| panic!("input with type '{}' has no selection", input.type_()))
+}
+
+/// Selects the text of the input from `start` to `end`, or moves the caret when they are equal.
+///
+/// Text typed with [`type_keys`] replaces the selected text and `Backspace` or `Delete` removes
+/// it.
+///
+/// # Panics
+/// If the type of input doesn't support selection, such as `email` or `number`.
+///
+/// # Examples
+/// ```
+/// use hyphae::event::*;
+/// use web_sys::HtmlInputElement;
+///
+/// # fn set_selection_range_example(input: HtmlInputElement) {
+/// let input: HtmlInputElement = // some function to get input element;
+///     # input;
+/// input.set_value("Hello World");
+/// set_selection_range(&input, 6, 11);
+/// type_keys(&input, "Rust");
+/// assert_eq!("Hello Rust", input.value());
+/// # }
+/// ```
+pub fn set_selection_range(input: &HtmlInputElement, start: u32, end: u32) {
+    input
+        .set_selection_range(start, end)
+        .unwrap_or_else(|_| panic!("input with type '{}' has no selection", input.type_()));
+}
+
+/// An element with an editable value and a selection.
+enum TextControl {
+    Input(HtmlInputElement),
+    TextArea(HtmlTextAreaElement),
+}
+
+impl TextControl {
+    fn new(element: &EventTarget) -> Option<Self> {
+        if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+            Some(Self::Input(input.clone()))
+        } else {
+            element
+                .dyn_ref::<HtmlTextAreaElement>()
+                .map(|textarea| Self::TextArea(textarea.clone()))
+        }
+    }
+
+    /// The start and end of the selection, which is [`None`] for the types of input that
+    /// don't support selection.
+    fn selection(&self) -> Option<(u32, u32)> {
+        let (start, end) = match self {
+            Self::Input(input) => (input.selection_start(), input.selection_end()),
+            Self::TextArea(textarea) => (textarea.selection_start(), textarea.selection_end()),
+        };
+        Some((start.ok()??, end.ok()??))
+    }
+
+    /// The length of the value in UTF-16 code units.
+    fn len(&self) -> u32 {
+        let value = match self {
+            Self::Input(input) => input.value(),
+            Self::TextArea(textarea) => textarea.value(),
+        };
+        value.encode_utf16().count() as u32
+    }
+
+    /// Replaces the text from `start` to `end` and moves the caret to the end of the new text.
+    fn replace(&self, text: &str, start: u32, end: u32) {
+        match self {
+            Self::Input(input) => input
+                .set_range_text_with_start_and_end_and_selection_mode(
+                    text,
+                    start,
+                    end,
+                    SelectionMode::End,
+                )
+                .unwrap(),
+            Self::TextArea(textarea) => textarea
+                .set_range_text_with_start_and_end_and_selection_mode(
+                    text,
+                    start,
+                    end,
+                    SelectionMode::End,
+                )
+                .unwrap(),
+        }
+    }
+}
+
+/// Enables dispatching a bubbling `change` event from an EventTarget
+pub trait EventTargetChanged {
+    /// Dispatches a change [`Event`] on this [`EventTarget`]
+    ///
+    /// # Examples
+    /// ```
+    /// use hyphae::event::EventTargetChanged;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// # fn dispatch_input_event_example(input: HtmlInputElement) {
+    /// let input: HtmlInputElement = // function to get input element
+    ///     # input;
+    /// // dispatch "change" event
+    /// input.changed();
+    /// # }
+    /// ```
+    fn changed(&self);
+}
+
+impl EventTargetChanged for EventTarget {
+    fn changed(&self) {
+        let mut event_init = EventInit::new();
+        event_init.bubbles(true);
+        let change_event = Event::new_with_event_init_dict("change", &event_init).unwrap();
+        assert!(self.dispatch_event(&change_event).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    use std::cell::Cell;
+
+    use wasm_bindgen::prelude::Closure;
+    use web_sys::{Document, HtmlElement, KeyboardEvent};
+
+    use hyphae::{prelude::*, QueryElement};
+    use hyphae_utils::make_element_with_html_string;
+
+    macro_rules! wasm_closure {
+        (|_: $t:ty| $expr:expr) => {
+            FunctionClosure(Closure::<dyn Fn($t)>::wrap(Box::new(|_: $t| $expr)))
+        };
+        (move |_: $t:ty| $expr:expr) => {
+            FunctionClosure(Closure::<dyn Fn($t)>::wrap(Box::new(move |_: $t| $expr)))
+        };
+        (| $($v:ident: $t:ty),* | $expr:expr) => {
+            FunctionClosure(Closure::<dyn Fn($($t),*)>::wrap(Box::new(|$($v: $t),*| $expr)))
+        };
+        (move | $($v:ident: $t:ty),* | $expr:expr) => {
+            FunctionClosure(Closure::<dyn Fn($($t),*)>::wrap(Box::new(move |$($v: $t),*| $expr)))
+        };
+    }
+
+    struct FunctionClosure<T: ?Sized>(Closure<T>);
+
+    impl<T: ?Sized> std::ops::Deref for FunctionClosure<T> {
+        type Target = js_sys::Function;
+
+        fn deref(&self) -> &Self::Target {
+            self.0.as_ref().unchecked_ref()
+        }
+    }
+
+    fn global_document() -> Document {
+        web_sys::window()
+            .expect("No global window object")
+            .document()
+            .expect("No global document object")
+    }
+
+    #[wasm_bindgen_test]
+    fn hash_change_updates_todo_filter() {
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<p id="filter">all</p>"#).into();
+        let filter: HtmlElement = rendered.get_by_text("all").unwrap();
+
+        let listener = {
+            let filter = filter.clone();
+            wasm_closure!(move |_: Event| {
+                let hash = web_sys::window().unwrap().location().hash().unwrap();
+                let name = match hash.as_str() {
+                    "#/active" => "active",
+                    "#/completed" => "completed",
+                    _ => "all",
+                };
+                filter.set_inner_text(name);
+            })
+        };
+        let window = web_sys::window().unwrap();
+        window
+            .add_event_listener_with_callback("hashchange", &listener)
+            .unwrap();
+
+        hash_change("#/completed");
+        assert_text_content!("completed", filter);
+
+        hash_change("/active");
+        assert_eq!("#/active", window.location().hash().unwrap());
+        assert_text_content!("active", filter);
+
+        hash_change("");
+        assert_text_content!("all", filter);
+
+        window
+            .remove_event_listener_with_callback("hashchange", &listener)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn sim_typing_to_input_and_enter_to_confirm() {
+        // setup
+
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <p id="key-value">None</p>
+            <label for="input">input label</label>
+            <input id="input" placeholder="key" type="text" />
+        "#,
+        )
+        .into();
+
+        let document = global_document();
+
+        let key_value_output = document
+            .get_element_by_id("key-value")
+            .expect("no element with 'key-value' id found")
             .unchecked_into::<HtmlElement>();
 
         let input = document
@@ -390,6 +1154,287 @@ mod tests {
         assert_eq!("hello", input.value());
     }
 
+    #[wasm_bindgen_test]
+    fn type_text_inserts_whole_grapheme_clusters() {
+        thread_local! {
+            static INPUT_DATA: std::cell::RefCell<Vec<String>> = Default::default();
+        }
+
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input placeholder="flag" type="text" />
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.get_by_placeholder_text("flag").unwrap();
+
+        let listener = wasm_closure!(move |e: InputEvent| {
+            INPUT_DATA.with(|v| v.borrow_mut().push(e.data().unwrap_or_default()));
+        });
+
+        input
+            .add_event_listener_with_callback("input", &listener)
+            .unwrap();
+
+        type_text(&input, "🇬🇧");
+
+        assert_eq!("🇬🇧", input.value());
+        // the flag is two code points but only one grapheme cluster
+        INPUT_DATA.with(|v| assert_eq!(vec!["🇬🇧".to_owned()], *v.borrow()));
+
+        input
+            .remove_event_listener_with_callback("input", &listener)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn simulate_typing_records_every_intermediate_value() {
+        thread_local! {
+            static VALUES: std::cell::RefCell<Vec<String>> = Default::default();
+        }
+
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input placeholder="input" type="text" />
+            <textarea placeholder="textarea"></textarea>
+            <div id="editable" contenteditable="true"></div>
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.get_by_placeholder_text("input").unwrap();
+        let textarea: HtmlElement = rendered.get_by_placeholder_text("textarea").unwrap();
+        let editable = global_document()
+            .get_element_by_id("editable")
+            .expect("no element with `editable` id found");
+
+        let recorder = wasm_closure!(move |e: Event| {
+            let target = e.target().unwrap();
+            let value = hyphae_utils::get_element_value(&target)
+                .or_else(|| target.unchecked_ref::<Node>().text_content())
+                .unwrap_or_default();
+            VALUES.with(|v| v.borrow_mut().push(value));
+        });
+
+        let expected: Vec<String> = vec!["h", "he", "hel", "hell", "hello"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+
+        for target in [
+            input.unchecked_ref::<EventTarget>(),
+            textarea.unchecked_ref(),
+            editable.unchecked_ref(),
+        ]
+        .iter()
+        {
+            target
+                .add_event_listener_with_callback("input", &recorder)
+                .unwrap();
+
+            simulate_typing(target, "hello");
+
+            VALUES.with(|v| assert_eq!(expected, v.replace(Vec::new())));
+
+            target
+                .remove_event_listener_with_callback("input", &recorder)
+                .unwrap();
+        }
+
+        assert_eq!("hello", input.value());
+        assert_eq!(Some("hello".to_owned()), editable.text_content());
+    }
+
+    #[wasm_bindgen_test]
+    fn set_input_value_native_updates_controlled_input() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input placeholder="controlled" type="text" />
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.get_by_placeholder_text("controlled").unwrap();
+
+        // track the value on the instance, like a framework with controlled inputs, and only
+        // count an input event as a change when the value differs from the tracked value
+        let track = js_sys::Function::new_with_args(
+            "input",
+            r#"
+            const { get, set } = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, 'value');
+            let tracked = input.value;
+            Object.defineProperty(input, 'value', {
+                configurable: true,
+                get() { return get.call(this); },
+                set(value) { tracked = value; set.call(this, value); },
+            });
+            input.addEventListener('input', () => {
+                if (input.value !== tracked) {
+                    tracked = input.value;
+                    input.dataset.changes = Number(input.dataset.changes || 0) + 1;
+                }
+            });
+        "#,
+        );
+        track.call1(&JsValue::NULL, &input).unwrap();
+
+        input.set_value("ignored");
+        let mut init = InputEventInit::new();
+        init.bubbles(true);
+        input
+            .dispatch_event(&InputEvent::new_with_event_init_dict("input", &init).unwrap())
+            .unwrap();
+        assert_eq!(None, input.get_attribute("data-changes"));
+
+        set_input_value_native(&input, "Hello, World!");
+        assert_eq!("Hello, World!", input.value());
+        assert_eq!(Some("1".to_owned()), input.get_attribute("data-changes"));
+    }
+
+    #[wasm_bindgen_test]
+    fn press_and_input_fires_keypress_and_input_once() {
+        thread_local! {
+            static EVENTS: std::cell::RefCell<Vec<String>> = Default::default();
+        }
+
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<input placeholder="name" type="text" />"#).into();
+        let input: HtmlInputElement = rendered.get_by_placeholder_text("name").unwrap();
+
+        let listener = wasm_closure!(move |e: Event| {
+            EVENTS.with(|v| v.borrow_mut().push(e.type_()));
+        });
+        for event_type in ["keypress", "input"].iter() {
+            input
+                .add_event_listener_with_callback(event_type, &listener)
+                .unwrap();
+        }
+
+        press_and_input(&input, 'a');
+
+        assert_eq!("a", input.value());
+        EVENTS.with(|v| assert_eq!(vec!["keypress", "input"], *v.borrow()));
+
+        for event_type in ["keypress", "input"].iter() {
+            input
+                .remove_event_listener_with_callback(event_type, &listener)
+                .unwrap();
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn type_to_effect_awaits_each_update() {
+        thread_local! {
+            static COUNTS: std::cell::RefCell<Vec<usize>> = Default::default();
+        }
+
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input placeholder="fruit" type="text" />
+            <p id="suggestions">3</p>
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.get_by_placeholder_text("fruit").unwrap();
+        let suggestions: HtmlElement = rendered.get_by_text("3").unwrap();
+
+        let listener = {
+            let input = input.clone();
+            let suggestions = suggestions.clone();
+            wasm_closure!(move |_: InputEvent| {
+                let value = input.value();
+                let count = ["apple", "apricot", "banana"]
+                    .iter()
+                    .filter(|fruit| fruit.starts_with(&value))
+                    .count();
+                COUNTS.with(|v| v.borrow_mut().push(count));
+                suggestions.set_inner_text(&count.to_string());
+            })
+        };
+
+        input
+            .add_event_listener_with_callback("input", &listener)
+            .unwrap();
+
+        type_to_effect(&input, "apr", Duration::from_millis(100)).await;
+
+        assert_eq!("apr", input.value());
+        assert_text_content!("1", suggestions);
+        COUNTS.with(|v| assert_eq!(vec![2, 2, 1], *v.borrow()));
+
+        input
+            .remove_event_listener_with_callback("input", &listener)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn reveal_panel_on_transition_end() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="panel" aria-hidden="true">Details</div>
+        "#,
+        )
+        .into();
+
+        let panel: HtmlElement = rendered.assert_first_by_selector("#panel");
+
+        let listener = {
+            let panel = panel.clone();
+            wasm_closure!(move |e: TransitionEvent| {
+                if e.property_name() == "opacity" {
+                    panel.set_attribute("aria-hidden", "false").unwrap();
+                }
+            })
+        };
+
+        panel
+            .add_event_listener_with_callback("transitionend", &listener)
+            .unwrap();
+
+        transition_end(&panel, "height");
+        assert_eq!(Some("true".to_owned()), panel.get_attribute("aria-hidden"));
+
+        transition_end(&panel, "opacity");
+        assert_eq!(Some("false".to_owned()), panel.get_attribute("aria-hidden"));
+
+        panel
+            .remove_event_listener_with_callback("transitionend", &listener)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn trigger_animation_end_event() {
+        thread_local! {
+            static ANIMATION: std::cell::RefCell<String> = Default::default();
+        }
+
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="toast">Saved</div>
+        "#,
+        )
+        .into();
+
+        let toast: HtmlElement = rendered.assert_first_by_selector("#toast");
+
+        let listener = wasm_closure!(|e: AnimationEvent| {
+            ANIMATION.with(|a| *a.borrow_mut() = e.animation_name());
+        });
+
+        rendered
+            .add_event_listener_with_callback("animationend", &listener)
+            .unwrap();
+
+        animation_end(&toast, "fade-in");
+        ANIMATION.with(|a| assert_eq!("fade-in", *a.borrow()));
+
+        rendered
+            .remove_event_listener_with_callback("animationend", &listener)
+            .unwrap();
+    }
+
     #[wasm_bindgen_test]
     fn trigger_on_change_event() {
         thread_local! {
@@ -422,4 +1467,243 @@ mod tests {
             .remove_event_listener_with_callback("change", &listener)
             .unwrap();
     }
+
+    #[wasm_bindgen_test]
+    fn reveal_image_on_load() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <img id="photo" alt="Sunset" hidden />
+            <p id="placeholder">Loading</p>
+        "#,
+        )
+        .into();
+
+        let img: HtmlImageElement = rendered.assert_first_by_selector("#photo");
+        let placeholder: HtmlElement = rendered.assert_first_by_selector("#placeholder");
+
+        let onload = {
+            let img = img.clone();
+            let placeholder = placeholder.clone();
+            wasm_closure!(move |_: Event| {
+                img.set_hidden(false);
+                placeholder.set_hidden(true);
+            })
+        };
+        img.set_onload(Some(&*onload));
+
+        assert!(img.hidden());
+        image_load(&img);
+        assert!(!img.hidden());
+        assert!(placeholder.hidden());
+
+        let onerror = {
+            let img = img.clone();
+            wasm_closure!(move |_: Event| img.set_alt("Image failed to load"))
+        };
+        img.set_onerror(Some(&*onerror));
+
+        image_error(&img);
+        assert_eq!("Image failed to load", img.alt());
+
+        img.set_onload(None);
+        img.set_onerror(None);
+    }
+
+    #[wasm_bindgen_test]
+    fn keyboard_reorder_moves_item_up() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul id="list">
+                <li id="first" tabindex="0" aria-grabbed="false">First</li>
+                <li id="second" tabindex="0" aria-grabbed="false">Second</li>
+                <li id="third" tabindex="0" aria-grabbed="false">Third</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let list: HtmlElement = rendered.assert_first_by_selector("#list");
+        let third: HtmlElement = rendered.assert_first_by_selector("#third");
+
+        // a minimal reorderable list following the ARIA drag and drop pattern
+        let listener = wasm_closure!(|e: KeyboardEvent| {
+            let item: Element = e.target().unwrap().unchecked_into();
+            let parent = item.parent_element().unwrap();
+            let grabbed = item.get_attribute("aria-grabbed").as_deref() == Some("true");
+            match e.key().as_str() {
+                " " => item
+                    .set_attribute("aria-grabbed", if grabbed { "false" } else { "true" })
+                    .unwrap(),
+                "ArrowUp" if grabbed => {
+                    if let Some(previous) = item.previous_element_sibling() {
+                        parent.insert_before(&item, Some(&previous)).unwrap();
+                    }
+                }
+                "ArrowDown" if grabbed => {
+                    if let Some(next) = item.next_element_sibling() {
+                        parent.insert_before(&next, Some(&item)).unwrap();
+                    }
+                }
+                _ => {}
+            }
+        });
+        list.add_event_listener_with_callback("keydown", &listener)
+            .unwrap();
+
+        keyboard_reorder(&third, -2);
+
+        let order: Vec<String> = rendered
+            .assert_all_by_selector::<Element>("li")
+            .map(|li| li.id())
+            .collect();
+        assert_eq!(vec!["third", "first", "second"], order);
+        assert_eq!(
+            Some("false".to_owned()),
+            third.get_attribute("aria-grabbed")
+        );
+
+        list.remove_event_listener_with_callback("keydown", &listener)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn full_click_presses_releases_and_clicks() {
+        thread_local! {
+            static EVENTS: std::cell::RefCell<Vec<String>> = Default::default();
+        }
+
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<button id="save">Save</button>"#).into();
+        let button: HtmlElement = rendered.get_by_text("Save").unwrap();
+
+        let listener = {
+            let button = button.clone();
+            wasm_closure!(move |e: Event| {
+                let event_type = e.type_();
+                let pressed = button.class_name() == "pressed";
+                match event_type.as_str() {
+                    "mousedown" => button.set_class_name("pressed"),
+                    "mouseup" => button.set_class_name(""),
+                    _ => button.set_attribute("data-saved", "true").unwrap(),
+                }
+                EVENTS.with(|v| v.borrow_mut().push(format!("{} {}", event_type, pressed)));
+            })
+        };
+        for event_type in ["mousedown", "mouseup", "click"].iter() {
+            button
+                .add_event_listener_with_callback(event_type, &listener)
+                .unwrap();
+        }
+
+        full_click(&button);
+
+        // each event with whether the button was pressed when it fired
+        EVENTS.with(|v| {
+            assert_eq!(
+                vec!["mousedown false", "mouseup true", "click false"],
+                *v.borrow()
+            )
+        });
+        assert_not_class!(button, "pressed");
+        assert_eq!(Some("true".to_owned()), button.get_attribute("data-saved"));
+
+        for event_type in ["mousedown", "mouseup", "click"].iter() {
+            button
+                .remove_event_listener_with_callback(event_type, &listener)
+                .unwrap();
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn focus_out_to_outside_wraps_focus_trap() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="dialog" role="dialog" aria-label="Confirm">
+                <button id="first">Yes</button>
+                <button id="last">No</button>
+            </div>
+            <button id="outside">Help</button>
+            <p id="wraps">0</p>
+        "#,
+        )
+        .into();
+
+        let document = global_document();
+        let get = |id: &str| {
+            document
+                .get_element_by_id(id)
+                .unwrap()
+                .unchecked_into::<HtmlElement>()
+        };
+        let dialog = get("dialog");
+        let (first, last, outside, wraps) =
+            (get("first"), get("last"), get("outside"), get("wraps"));
+
+        let focus_trap = {
+            let dialog = dialog.clone();
+            let first = first.clone();
+            wasm_closure!(move |e: web_sys::FocusEvent| {
+                let related = e.related_target().and_then(|t| t.dyn_into::<Node>().ok());
+                if !dialog.contains(related.as_ref()) {
+                    let count: u32 = wraps.inner_text().parse().unwrap();
+                    wraps.set_inner_text(&(count + 1).to_string());
+                    first.focus().unwrap();
+                }
+            })
+        };
+        dialog
+            .add_event_listener_with_callback("focusout", &focus_trap)
+            .unwrap();
+
+        // focus moving within the dialog doesn't wrap
+        focus_out(&first, Some(&last));
+        assert_text_content!("0", get("wraps"));
+
+        focus_out(&last, Some(&outside));
+        assert_text_content!("1", get("wraps"));
+
+        dialog
+            .remove_event_listener_with_callback("focusout", &focus_trap)
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn typing_replaces_selection_and_moves_caret() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input placeholder="greeting" type="text" />
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.get_by_placeholder_text("greeting").unwrap();
+        input.set_value("Hello World");
+        assert_eq!((11, 11), selection_range(&input));
+
+        set_selection_range(&input, 6, 11);
+        assert_eq!((6, 11), selection_range(&input));
+
+        type_keys(&input, "Rust");
+        assert_eq!("Hello Rust", input.value());
+        assert_eq!((10, 10), selection_range(&input));
+
+        // type in the middle of the value
+        set_selection_range(&input, 5, 5);
+        type_keys(&input, ",");
+        assert_eq!("Hello, Rust", input.value());
+        assert_eq!((6, 6), selection_range(&input));
+
+        type_key(&input, Key::Backspace);
+        assert_eq!("Hello Rust", input.value());
+        assert_eq!((5, 5), selection_range(&input));
+
+        type_key(&input, Key::Delete);
+        assert_eq!("HelloRust", input.value());
+        assert_eq!((5, 5), selection_range(&input));
+
+        set_selection_range(&input, 0, 5);
+        type_key(&input, Key::Backspace);
+        assert_eq!("Rust", input.value());
+        assert_eq!((0, 0), selection_range(&input));
+    }
 }
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use wasm_bindgen::JsCast;
-use web_sys::NodeList;
+use web_sys::{Node, NodeList};
 
 /// Iterator for [`Element`](web_sys::Element)s
 pub struct ElementIter<'a, T: JsCast> {
@@ -10,7 +10,7 @@ pub struct ElementIter<'a, T: JsCast> {
 }
 
 #[allow(dead_code)]
-impl<T: JsCast> ElementIter<'_, T> {
+impl<'a, T: JsCast> ElementIter<'a, T> {
     pub(crate) fn new(node_list: Option<NodeList>) -> Self {
         if let Some(node_list) = node_list {
             node_list.into()
@@ -21,6 +21,41 @@ impl<T: JsCast> ElementIter<'_, T> {
             }
         }
     }
+
+    pub(crate) fn from_elements<I>(elements: I) -> Self
+    where
+        I: Iterator<Item = T> + 'a,
+    {
+        Self {
+            iter: Box::new(elements),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: JsCast> ElementIter<'_, T> {
+    /// Returns the index of `element` in the remaining elements of the iterator.
+    ///
+    /// Elements are compared by identity, using [`Node::is_same_node`], so this can be used to
+    /// check that an element found by one query is the same node as an element found by another.
+    ///
+    /// Like [`Iterator::position`] this consumes the elements up to and including `element`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let delete: HtmlButtonElement = rendered.assert_by_text("Delete milk");
+    /// let position = rendered
+    ///     .assert_all_by_selector::<HtmlButtonElement>("li button")
+    ///     .position_of(&delete);
+    /// assert_eq!(Some(2), position);
+    /// ```
+    pub fn position_of(&mut self, element: &Node) -> Option<usize> {
+        self.position(|e| e.unchecked_ref::<Node>().is_same_node(Some(element)))
+    }
 }
 
 impl<T: JsCast> From<NodeList> for ElementIter<'_, T> {
@@ -100,3 +135,30 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae::prelude::*;
+    use web_sys::HtmlButtonElement;
+
+    #[wasm_bindgen_test]
+    fn position_of_element_found_by_another_query() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <ul>
+                <li>Eggs <button>Delete eggs</button></li>
+                <li>Bread <button>Delete bread</button></li>
+                <li>Milk <button>Delete milk</button></li>
+            </ul>
+        "#,
+        );
+
+        let delete: HtmlButtonElement = rendered.assert_by_text("Delete milk");
+        let mut buttons = rendered.assert_all_by_selector::<HtmlButtonElement>("li button");
+        assert_eq!(Some(2), buttons.position_of(&delete));
+        assert_eq!(None, buttons.position_of(&delete));
+    }
+}
@@ -3,19 +3,32 @@
 
 extern crate self as hyphae;
 
+pub mod aria;
 mod asserts;
+pub mod debug;
 pub mod event;
 mod iter;
 pub mod queries;
+pub mod stale;
 
 /// Utility functions.
 pub mod utils {
-    pub use hyphae_utils::{effect_dom, wait_ms};
+    pub use hyphae_utils::{
+        class_list, closest, effect_dom, effect_dom_until, flush_updates, fuzzy_eq, is_close,
+        suggest, wait_ms,
+    };
 }
 
 pub use iter::*;
 pub use queries::QueryElement;
 
+/// Attribute to mark a test that runs in the browser, see
+/// [`hyphae_test`](hyphae_test_macros::hyphae_test).
+///
+/// _Requires the `macros` feature._
+#[cfg(feature = "macros")]
+pub use hyphae_test_macros::hyphae_test;
+
 /// Alias for boxed error
 pub type Error = Box<dyn std::error::Error>;
 
@@ -23,18 +36,39 @@ pub type Error = Box<dyn std::error::Error>;
 ///
 /// Convenient module to import the most used imports for hyphae.
 ///
+/// This includes the queries, asserts and the helpers to fire events.
+///
 /// ```no_run
 /// use hyphae::prelude::*;
 /// ```
+///
+/// Only the prelude is required to query for an element and type to it:
+/// ```no_run
+/// use hyphae::prelude::*;
+/// use web_sys::HtmlInputElement;
+///
+/// # fn prelude_example() {
+/// let rendered = QueryElement::new();
+/// let input: HtmlInputElement = rendered.assert_by_aria_role(AriaRole::TextBox, "Username");
+/// type_to!(input, "mc1098", Key::Enter);
+/// input.changed();
+/// # }
+/// ```
 pub mod prelude {
     pub use hyphae::{
-        assert_inner_text, assert_text_content,
+        aria::*,
+        assert_count, assert_has_class, assert_inner_text, assert_not_class, assert_text_content,
+        assert_valuetext,
+        event::*,
         iter::*,
+        pretty_assert,
         queries::{
-            by_aria::*, by_display_value::*, by_label_text::*, by_placeholder_text::*,
-            by_selector::*, by_text::*, QueryElement,
+            by_aria::*, by_display_value::*, by_form::*, by_label_text::*, by_lang::*,
+            by_placeholder_text::*, by_selector::*, by_text::*, chain::*, MatchOptions,
+            QueryElement,
         },
-        Error,
+        stale::*,
+        type_to, Error,
     };
     pub use hyphae_aria::{property::*, role::*, state::*};
 }
@@ -172,17 +172,31 @@
 //! version does not need to be explicitly set.
 //!
 //! [A table of native HTML features aria-* attribute parity.](https://www.w3.org/TR/html-aria/#docconformance-attr)
+//!
+//! # Shadow DOM
+//!
+//! Queries search the light DOM of the root element, so the content that a web component slots
+//! into its shadow root is found, as slotted elements are children of the component in the light
+//! DOM:
+//!
+//! ```html
+//! <my-toolbar>
+//!     <button>Save</button>
+//!     ^^^^^^^^^^^^^^^^^^^^^ assigned to a `slot` in the shadow root and found by queries
+//! </my-toolbar>
+//! ```
+//! The elements inside of a shadow root are not searched.
 
 use std::fmt::{Debug, Display};
 
-use hyphae::{Error, QueryElement, RawNodeListIter};
+use hyphae::{queries::MatchOptions, ElementIter, Error, QueryElement, RawNodeListIter};
 use hyphae_aria::{
     element_accessible_name, property::AriaProperty, role::AriaRole, state::AriaState,
     ToQueryString,
 };
 
-use wasm_bindgen::JsCast;
-use web_sys::{Element, Node};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, HtmlElement, HtmlMeterElement, Node};
 
 /// Enables querying elements generically by ARIA roles, properties, and state.
 ///
@@ -280,39 +294,62 @@ pub trait ByAria {
     where
         T: JsCast;
 
-    /// Get a generic element by ARIA property and optional accessible name.
+    /// Expect an element by ARIA role and accessible name, returning the error for use with the
+    /// `?` operator.
     ///
-    /// Some [`AriaProperty`] are so descriptive that the accessible name is not required, such is the
-    /// case with [`AriaProperty::Label`] [^note] - the content of this property is the accessible name.
-    /// When this is the case the accessible name can be [`None`].
+    /// This is the same as [`get_by_aria_role`](ByAria::get_by_aria_role) but reads like an
+    /// assertion in a test that returns a [`Result`]. Unlike
+    /// [`assert_by_aria_role`](ByAria::assert_by_aria_role) it doesn't panic, so the rendered
+    /// element is removed when the [`QueryElement`] is dropped as the error is returned.
     ///
-    /// Using an explicit element type as `T` will essentially skip the other types of elements - if
-    /// you want to find the very first element that matches the ARIA property and accessible name
-    /// then use [`HtmlElement`](web_sys::HtmlElement).
+    /// # Examples
     ///
-    /// [^note]: _If an accessible name is provided then it must match the content of
-    /// [`AriaProperty::Label`]._
+    /// Rendered html:
+    /// ```html
+    /// <button id="submit">Submit</button>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::{prelude::*, Error};
+    /// use web_sys::HtmlButtonElement;
     ///
-    /// # Panics
+    /// #[wasm_bindgen_test]
+    /// fn submit_button() -> Result<(), Error> {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
     ///
-    /// _Nothing to see here._
+    ///     let submit: HtmlButtonElement = rendered.expect_by_aria_role(AriaRole::Button, "Submit")?;
+    ///     assert_eq!("submit", submit.id());
+    ///     Ok(())
+    /// }
+    /// ```
+    fn expect_by_aria_role<T>(&self, role: AriaRole, name: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        self.get_by_aria_role(role, name)
+    }
+
+    /// Get a generic element by ARIA role and accessible name using the [`MatchOptions`] given.
     ///
-    /// # Examples
+    /// [`get_by_aria_role`](ByAria::get_by_aria_role) uses the default [`MatchOptions`] which
+    /// will skip elements that are hidden from the user, such as the content of a closed `details`
+    /// element or an `aria-hidden="true"` subtree. Use [`MatchOptions::include_hidden`] to find
+    /// these elements as well.
     ///
-    /// ## Get input by required property and accessible name
+    /// # Examples
     ///
-    /// There are two required inputs in the form and so the accessible name is needed to differentiate
-    /// between the two. We use the [`AriaProperty::Required`] enum variant which accepts a `bool` to
-    /// assert that the element is required.
+    /// ## Get button in a closed details element
     ///
     /// Rendered html:
     /// ```html
-    /// <form>
-    ///     <label for="user-email">Email:</label>
-    ///     <input type="email" id="user-email" required />
-    ///     <label for="user-password">Password:</label>
-    ///     <input type="password" id="user-password" required />
-    /// </form>
+    /// <details>
+    ///     <summary>More options</summary>
+    ///     <button id="mybtn">Delete</button>
+    /// </details>
     /// ```
     /// Code:
     /// ```no_run
@@ -320,33 +357,59 @@ pub trait ByAria {
     /// use wasm_bindgen_test::*;
     /// wasm_bindgen_test_configure!(run_in_browser);
     /// use hyphae::prelude::*;
-    /// use web_sys::HtmlInputElement;
+    /// use web_sys::HtmlButtonElement;
     ///
     /// #[wasm_bindgen_test]
-    /// fn get_required_input_by_name() {
+    /// fn get_hidden_button_by_role() {
     ///     let rendered: QueryElement = // feature dependent rendering
     ///         # QueryElement::new();
     ///
-    ///     let email_input: HtmlInputElement = rendered
-    ///         .get_by_aria_prop(AriaProperty::Required(true), "Email:")
-    ///         .expect("to find required email input");
+    ///     let options = MatchOptions {
+    ///         include_hidden: true,
+    ///         ..Default::default()
+    ///     };
+    ///     let button: HtmlButtonElement = rendered
+    ///         .get_by_aria_role_with_options(AriaRole::Button, "Delete", options)
+    ///         .expect("to find the button even though details is closed");
     ///
-    ///     assert_eq!("user-email", email_input.id());
+    ///     assert_eq!("mybtn", button.id());
     /// }
     /// ```
+    fn get_by_aria_role_with_options<T>(
+        &self,
+        role: AriaRole,
+        name: &str,
+        options: MatchOptions,
+    ) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_by_aria_role_with_options`](ByAria::get_by_aria_role_with_options).
+    fn assert_by_aria_role_with_options<T>(
+        &self,
+        role: AriaRole,
+        name: &str,
+        options: MatchOptions,
+    ) -> T
+    where
+        T: JsCast;
+
+    /// Get a generic element by ARIA role that passes the `predicate` given.
     ///
-    /// ## Get button by aria-label
+    /// The elements with the ARIA role are checked in document order and the first one for which
+    /// the `predicate` returns `true` is returned. This is an escape hatch for filtering elements
+    /// in ways the other queries don't support.
     ///
-    /// Getting a button like this instead of by role and accessible name is probably not what you want
-    /// to do, but this does assert that this element's accessible name won't change due to aria-label
-    /// being set.
+    /// # Examples
+    ///
+    /// ## Get the primary button
     ///
     /// Rendered html:
     /// ```html
     /// <div>
-    ///     <div id="not-mybtn">
-    ///         <button id="mybtn" aria-label="ok" /> <!-- No text on button -->
-    ///     </div>
+    ///     <button id="cancel" data-variant="secondary">Cancel</button>
+    ///     <button id="save" data-variant="primary">Save</button>
     /// </div>
     /// ```
     /// Code:
@@ -358,56 +421,81 @@ pub trait ByAria {
     /// use web_sys::HtmlButtonElement;
     ///
     /// #[wasm_bindgen_test]
-    /// fn get_button_aria_label() {
+    /// fn get_primary_button() {
     ///     let rendered: QueryElement = // feature dependent rendering
     ///         # QueryElement::new();
     ///
     ///     let button: HtmlButtonElement = rendered
-    ///         .get_by_aria_prop(AriaProperty::Label("ok".to_owned()), None)
-    ///         .expect("to get button by it's aria-label value");
+    ///         .get_by_aria_role_where(AriaRole::Button, |el| {
+    ///             el.get_attribute("data-variant").as_deref() == Some("primary")
+    ///         })
+    ///         .unwrap();
     ///
-    ///     assert_eq!("mybtn", button.id());
+    ///     assert_eq!("save", button.id());
     /// }
     /// ```
-    fn get_by_aria_prop<'name, S, T>(&self, property: AriaProperty, name: S) -> Result<T, Error>
+    fn get_by_aria_role_where<T, F>(&self, role: AriaRole, predicate: F) -> Result<T, Error>
     where
-        S: Into<Option<&'name str>>,
-        T: JsCast;
+        T: JsCast,
+        F: Fn(&HtmlElement) -> bool;
 
-    /// A convenient method which unwraps the result of [`get_by_aria_prop`](ByAria::get_by_aria_prop).
-    fn assert_by_aria_prop<'name, S, T>(&self, property: AriaProperty, name: S) -> T
+    /// A convenient method which unwraps the result of
+    /// [`get_by_aria_role_where`](ByAria::get_by_aria_role_where).
+    fn assert_by_aria_role_where<T, F>(&self, role: AriaRole, predicate: F) -> T
     where
-        S: Into<Option<&'name str>>,
-        T: JsCast;
+        T: JsCast,
+        F: Fn(&HtmlElement) -> bool;
 
-    /// Get a generic element by ARIA state and optional accessible name.
+    /// Get a generic element by ARIA role and accessible name, along with the accessible name
+    /// computed for the element.
     ///
-    /// Some [`AriaState`] can be so descriptive in subsections of the DOM that an accessible name is
-    /// not required to identify a single element, such is the case with [`AriaState::Selected`] on a
-    /// subsection that can only have a single selected item. When the accessible name is not required
-    /// the value can be [`None`].
+    /// This is the same as [`get_by_aria_role`](ByAria::get_by_aria_role) but avoids computing
+    /// the accessible name again when you want to assert on it.
     ///
-    /// Using an explicit element type as `T` will essentially skip the other types of elements - if
-    /// you want to find the very first element that matches the ARIA state and accessible name then
-    /// use [`HtmlElement`](web_sys::HtmlElement).
+    /// # Examples
     ///
-    /// # Panics
+    /// Rendered html:
+    /// ```html
+    /// <button aria-label="Close dialog">X</button>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
     ///
-    /// _Nothing to see here._
+    /// #[wasm_bindgen_test]
+    /// fn get_close_button_and_name() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
     ///
-    /// # Examples
+    ///     let (button, name): (HtmlButtonElement, String) = rendered
+    ///         .get_by_aria_role_named(AriaRole::Button, "Close dialog")
+    ///         .unwrap();
     ///
-    /// ## Get input with invalid spelling
+    ///     assert_eq!("Close dialog", name);
+    /// }
+    /// ```
+    fn get_by_aria_role_named<T>(&self, role: AriaRole, name: &str) -> Result<(T, String), Error>
+    where
+        T: JsCast;
+
+    /// Get all of the generic elements with the ARIA role and accessible name, in document order.
     ///
-    /// One input element has a misspelled animal value which has been validated and the
-    /// "aria-invalid=spelling" state has been applied.
+    /// Like [`get_by_aria_role`](ByAria::get_by_aria_role) hidden elements are skipped, however,
+    /// only elements with an accessible name equal to `name` are included - there are no close
+    /// matches. The iterator is empty when no element matches.
+    ///
+    /// # Examples
     ///
     /// Rendered html:
     /// ```html
-    /// <form>
-    /// <input id="best-pet" aria-invalid="spelling" value="doge" aria-label="best pet" />
-    /// <input id="second-best-pet" value="cat" aria-label="second best pet" />
-    /// </form>
+    /// <ul>
+    ///     <li>Milk <button>Remove</button></li>
+    ///     <li>Eggs <button>Remove</button></li>
+    /// </ul>
     /// ```
     /// Code:
     /// ```no_run
@@ -415,96 +503,1205 @@ pub trait ByAria {
     /// use wasm_bindgen_test::*;
     /// wasm_bindgen_test_configure!(run_in_browser);
     /// use hyphae::prelude::*;
-    /// use web_sys::HtmlInputElement;
+    /// use web_sys::HtmlButtonElement;
     ///
     /// #[wasm_bindgen_test]
-    /// fn get_invalid_spelling_input() {
-    /// let rendered: QueryElement = // feature dependent rendering
-    /// # QueryElement::new();
-    ///
-    /// let spelling_error_input: HtmlInputElement = rendered
-    /// .get_by_aria_state(AriaState::Invalid(InvalidToken::Spelling), "best pet")
-    /// .expect("expect to find input by spelling error - doge is a typo!");
+    /// fn get_all_remove_buttons() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
     ///
-    /// assert_eq!("best-pet", spelling_error_input.id());
+    ///     let removes = rendered.get_all_by_aria_role_named::<HtmlButtonElement>(
+    ///         AriaRole::Button,
+    ///         "Remove",
+    ///     );
+    ///     assert_eq!(2, removes.count());
     /// }
     /// ```
-    fn get_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> Result<T, Error>
+    fn get_all_by_aria_role_named<T>(&self, role: AriaRole, name: &str) -> ElementIter<T>
     where
-        S: Into<Option<&'name str>>,
         T: JsCast;
 
-    /// A convenient method which unwraps the result of [`get_by_aria_state`](ByAria::get_by_aria_state).
-    fn assert_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> T
+    /// Get a generic element by ARIA role and the value of its own `aria-label` attribute.
+    ///
+    /// Unlike [`get_by_aria_role`](ByAria::get_by_aria_role) the accessible name isn't computed,
+    /// so an element is only found when the `aria-label` is on the element with the role and is
+    /// equal to `aria_label`. Text content and `aria-labelledby` references are ignored, which
+    /// makes this query stricter and faster.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <button id="close" aria-label="Close">X</button>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_close_button_by_aria_label() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let close: HtmlButtonElement = rendered
+    ///         .get_by_aria_role_labelled(AriaRole::Button, "Close")
+    ///         .unwrap();
+    ///     assert_eq!("close", close.id());
+    /// }
+    /// ```
+    fn get_by_aria_role_labelled<T>(&self, role: AriaRole, aria_label: &str) -> Result<T, Error>
     where
-        S: Into<Option<&'name str>>,
         T: JsCast;
-}
-
-#[inline]
-fn get_by_aria_impl<S, T>(root: &Element, aria: S, name: Option<&str>) -> Result<T, Error>
-where
-    S: ToQueryString,
-    T: JsCast,
-{
-    let node_list = root.query_selector_all(&aria.to_query_string()).ok();
-    let mut node_iter = RawNodeListIter::<T>::new(node_list);
-    if let Some(name) = name {
-        let elements = node_iter.filter_map(|element| {
-            Some((
-                element_accessible_name(element.unchecked_ref()).ok()?,
-                element,
-            ))
-        });
-
-        if let Some((an, e)) = hyphae_utils::closest(name, elements, |(k, _)| k) {
-            if an == name {
-                Ok(e)
-            } else {
-                Err(Box::new(ByAriaError::Closest {
-                    name: name.to_owned(),
-                    inner_html: root.inner_html(),
-                    closest_node: e.unchecked_into(),
-                }))
-            }
-        } else {
-            Err(Box::new(ByAriaError::NotFound {
-                name: Some(name.to_owned()),
-                inner_html: root.inner_html(),
-            }))
-        }
-    } else if let Some(element) = node_iter.next() {
-        Ok(element)
-    } else {
-        Err(Box::new(ByAriaError::NotFound {
-            name: None,
-            inner_html: root.inner_html(),
-        }))
-    }
-}
 
-impl ByAria for QueryElement {
-    fn assert_by_aria_role<T>(&self, role: AriaRole, name: &str) -> T
+    /// A convenient method which unwraps the result of
+    /// [`get_by_aria_role_labelled`](ByAria::get_by_aria_role_labelled).
+    fn assert_by_aria_role_labelled<T>(&self, role: AriaRole, aria_label: &str) -> T
     where
-        T: JsCast,
-    {
-        let result = self.get_by_aria_role(role, name);
-        if result.is_err() {
-            self.remove();
-        }
-        result.unwrap()
-    }
+        T: JsCast;
 
-    fn get_by_aria_role<T>(&self, role: AriaRole, name: &str) -> Result<T, Error>
+    /// Get a `menuitem` by accessible name, skipping disabled menu items.
+    ///
+    /// A menu item is disabled when it has `aria-disabled="true"`, is a disabled form control or
+    /// is in a menu with `aria-disabled="true"`.
+    /// When there are many menu items with the same accessible name the first one that is enabled
+    /// is returned.
+    ///
+    /// # Errors
+    /// When only disabled menu items have the accessible name the error will say that the menu
+    /// item is disabled.
+    ///
+    /// # Examples
+    /// Rendered html:
+    /// ```html
+    /// <ul role="menu">
+    ///     <li role="menuitem" aria-disabled="true">Paste</li>
+    ///     <li id="copy" role="menuitem">Copy</li>
+    /// </ul>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_enabled_menu_item() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let copy: HtmlElement = rendered.get_menu_item("Copy").unwrap();
+    ///     assert_eq!("copy", copy.id());
+    ///     assert!(rendered.get_menu_item::<HtmlElement>("Paste").is_err());
+    /// }
+    /// ```
+    fn get_menu_item<T>(&self, name: &str) -> Result<T, Error>
     where
-        T: JsCast,
-    {
-        get_by_aria_impl(self, role, name.into())
-    }
+        T: JsCast;
 
-    fn assert_by_aria_prop<'name, S, T>(&self, property: AriaProperty, name: S) -> T
+    /// Select an `option` in a listbox by accessible name, skipping disabled options.
+    ///
+    /// The option is found the same way as [`get_menu_item`](ByAria::get_menu_item) finds a menu
+    /// item and is then clicked to select it.
+    ///
+    /// # Errors
+    /// When only disabled options have the accessible name the error will say that the option is
+    /// disabled, and the option is not clicked.
+    ///
+    /// # Examples
+    /// Rendered html:
+    /// ```html
+    /// <ul role="listbox" aria-label="Fruit">
+    ///     <li role="option" aria-disabled="true">Apple</li>
+    ///     <li id="banana" role="option">Banana</li>
+    /// </ul>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn select_enabled_option() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let banana: HtmlElement = rendered.select_listbox_option("Banana").unwrap();
+    ///     assert_eq!("banana", banana.id());
+    /// }
+    /// ```
+    fn select_listbox_option<T>(&self, name: &str) -> Result<T, Error>
     where
-        S: Into<Option<&'name str>>,
+        T: JsCast;
+
+    /// Assert that an element with the ARIA role and accessible name exists.
+    ///
+    /// This is the same as [`assert_by_aria_role`](ByAria::assert_by_aria_role) but discards the
+    /// element found, which reads better in tests that are checking accessibility rather than
+    /// fetching an element.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <button>Submit</button>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn submit_button_is_accessible() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     rendered.assert_accessible(AriaRole::Button, "Submit");
+    /// }
+    /// ```
+    fn assert_accessible(&self, role: AriaRole, name: &str);
+
+    /// Assert that no element with the ARIA role and accessible name exists.
+    ///
+    /// This is the inverse of [`assert_accessible`](ByAria::assert_accessible) and gives a
+    /// clearer failure than asserting that [`get_by_aria_role`](ByAria::get_by_aria_role) is an
+    /// error, as the element that was unexpectedly found is in the panic message.
+    ///
+    /// # Panics
+    /// If an element with the ARIA role and accessible name is found, the rendered root element
+    /// is removed and the panic message includes the element found.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <button>Save</button>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn delete_button_is_not_shown() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     rendered.assert_no_role(AriaRole::Button, "Delete");
+    /// }
+    /// ```
+    fn assert_no_role(&self, role: AriaRole, name: &str);
+
+    /// Get a list item by accessible name at a nesting level of lists.
+    ///
+    /// The `level` of a list item is the number of lists it is nested in, so an item of a top
+    /// level list has a `level` of `1` and an item of a list nested in that item has a `level` of
+    /// `2`. This is the level a screen reader announces for nested lists.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <ul>
+    ///     <li>Fruit
+    ///         <ul>
+    ///             <li id="nested-apple">Apple</li>
+    ///         </ul>
+    ///     </li>
+    ///     <li>Apple</li>
+    /// </ul>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_nested_apple() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let apple: HtmlElement = rendered.get_list_item("Apple", 2).unwrap();
+    ///     assert_eq!("nested-apple", apple.id());
+    /// }
+    /// ```
+    fn get_list_item<T>(&self, name: &str, level: usize) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of [`get_list_item`](ByAria::get_list_item).
+    fn assert_list_item<T>(&self, name: &str, level: usize) -> T
+    where
+        T: JsCast;
+
+    /// Get the current value of a `meter` by accessible name.
+    ///
+    /// The value is the `aria-valuenow` of the meter when it has one, otherwise the value of a
+    /// native `meter` element, which is clamped between its `min` and `max`.
+    ///
+    /// # Errors
+    /// When no meter has the accessible name, or the meter has no `aria-valuenow` and is not a
+    /// native `meter` element.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <label for="disk">Disk usage</label>
+    /// <meter id="disk" min="0" max="100" value="72"></meter>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn disk_usage_value() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     assert_eq!(72.0, rendered.get_meter_value("Disk usage").unwrap());
+    /// }
+    /// ```
+    fn get_meter_value(&self, name: &str) -> Result<f64, Error>;
+
+    /// A convenient method which unwraps the result of [`get_meter_value`](ByAria::get_meter_value).
+    fn assert_meter_value(&self, name: &str) -> f64;
+
+    /// Get a generic element by accessible name alone, whatever the ARIA role of the element.
+    ///
+    /// Elements without a specific role, such as a `div`, get their accessible name from their
+    /// content, so a wrapper element will often have the same accessible name as the element it
+    /// wraps. When this happens the innermost element is returned.
+    ///
+    /// _Note: Every element in the root element has its accessible name computed, which can be
+    /// slow for large trees. Prefer [`get_by_aria_role`](ByAria::get_by_aria_role) when the role
+    /// is known._
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <div>
+    ///     <button id="save">Save</button>
+    /// </div>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_save_by_name() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let save: HtmlElement = rendered.get_by_name("Save").unwrap();
+    ///     assert_eq!("save", save.id());
+    /// }
+    /// ```
+    fn get_by_name<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of [`get_by_name`](ByAria::get_by_name).
+    fn assert_by_name<T>(&self, name: &str) -> T
+    where
+        T: JsCast;
+
+    /// Get a generic element by ARIA role and accessible name that is also in the ARIA state given.
+    ///
+    /// The element must match both the role and the state, which is useful when there are many
+    /// elements with the same role and name, but only one is in the state you are looking for.
+    ///
+    /// # Examples
+    ///
+    /// ## Get the expanded combobox
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <div>
+    ///     <input id="collapsed" role="combobox" aria-label="city" aria-expanded="false" />
+    ///     <input id="expanded" role="combobox" aria-label="city" aria-expanded="true" />
+    /// </div>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_expanded_combobox() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let combobox: HtmlInputElement = rendered
+    ///         .get_by_aria_role_with_state(
+    ///             AriaRole::Combobox,
+    ///             AriaState::Expanded(DuoState::True),
+    ///             "city",
+    ///         )
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("expanded", combobox.id());
+    /// }
+    /// ```
+    fn get_by_aria_role_with_state<T>(
+        &self,
+        role: AriaRole,
+        state: AriaState,
+        name: &str,
+    ) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_by_aria_role_with_state`](ByAria::get_by_aria_role_with_state).
+    fn assert_by_aria_role_with_state<T>(&self, role: AriaRole, state: AriaState, name: &str) -> T
+    where
+        T: JsCast;
+
+    /// Get a generic element by ARIA role, ARIA property and accessible name.
+    ///
+    /// This is similar to [`get_by_aria_role_with_state`](ByAria::get_by_aria_role_with_state)
+    /// but the element must also have the [`AriaProperty`] given, such as picking one of two
+    /// sliders by [`AriaProperty::Orientation`].
+    ///
+    /// _Note: Only the attribute is checked, so a slider without `aria-orientation` isn't
+    /// matched by
+    /// [`OrientationToken::Horizontal`](hyphae_aria::property::OrientationToken::Horizontal) even
+    /// though that is the implicit value._
+    ///
+    /// # Examples
+    ///
+    /// ## Get the vertical slider of a colour picker
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <div>
+    ///     <div id="saturation" role="slider" aria-label="colour" aria-orientation="horizontal"></div>
+    ///     <div id="lightness" role="slider" aria-label="colour" aria-orientation="vertical"></div>
+    /// </div>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_vertical_slider() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let slider: HtmlElement = rendered
+    ///         .get_by_aria_role_with_prop(
+    ///             AriaRole::Slider,
+    ///             AriaProperty::Orientation(OrientationToken::Vertical),
+    ///             "colour",
+    ///         )
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("lightness", slider.id());
+    /// }
+    /// ```
+    fn get_by_aria_role_with_prop<T>(
+        &self,
+        role: AriaRole,
+        property: AriaProperty,
+        name: &str,
+    ) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_by_aria_role_with_prop`](ByAria::get_by_aria_role_with_prop).
+    fn assert_by_aria_role_with_prop<T>(
+        &self,
+        role: AriaRole,
+        property: AriaProperty,
+        name: &str,
+    ) -> T
+    where
+        T: JsCast;
+
+    /// Get a generic element by ARIA property and optional accessible name.
+    ///
+    /// Some [`AriaProperty`] are so descriptive that the accessible name is not required, such is the
+    /// case with [`AriaProperty::Label`] [^note] - the content of this property is the accessible name.
+    /// When this is the case the accessible name can be [`None`].
+    ///
+    /// Using an explicit element type as `T` will essentially skip the other types of elements - if
+    /// you want to find the very first element that matches the ARIA property and accessible name
+    /// then use [`HtmlElement`](web_sys::HtmlElement).
+    ///
+    /// [^note]: _If an accessible name is provided then it must match the content of
+    /// [`AriaProperty::Label`]._
+    ///
+    /// # Panics
+    ///
+    /// _Nothing to see here._
+    ///
+    /// # Examples
+    ///
+    /// ## Get input by required property and accessible name
+    ///
+    /// There are two required inputs in the form and so the accessible name is needed to differentiate
+    /// between the two. We use the [`AriaProperty::Required`] enum variant which accepts a `bool` to
+    /// assert that the element is required.
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <form>
+    ///     <label for="user-email">Email:</label>
+    ///     <input type="email" id="user-email" required />
+    ///     <label for="user-password">Password:</label>
+    ///     <input type="password" id="user-password" required />
+    /// </form>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_required_input_by_name() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let email_input: HtmlInputElement = rendered
+    ///         .get_by_aria_prop(AriaProperty::Required(true), "Email:")
+    ///         .expect("to find required email input");
+    ///
+    ///     assert_eq!("user-email", email_input.id());
+    /// }
+    /// ```
+    ///
+    /// ## Get button by aria-label
+    ///
+    /// Getting a button like this instead of by role and accessible name is probably not what you want
+    /// to do, but this does assert that this element's accessible name won't change due to aria-label
+    /// being set.
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <div>
+    ///     <div id="not-mybtn">
+    ///         <button id="mybtn" aria-label="ok" /> <!-- No text on button -->
+    ///     </div>
+    /// </div>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_button_aria_label() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///
+    ///     let button: HtmlButtonElement = rendered
+    ///         .get_by_aria_prop(AriaProperty::Label("ok".to_owned()), None)
+    ///         .expect("to get button by it's aria-label value");
+    ///
+    ///     assert_eq!("mybtn", button.id());
+    /// }
+    /// ```
+    fn get_by_aria_prop<'name, S, T>(&self, property: AriaProperty, name: S) -> Result<T, Error>
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of [`get_by_aria_prop`](ByAria::get_by_aria_prop).
+    fn assert_by_aria_prop<'name, S, T>(&self, property: AriaProperty, name: S) -> T
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast;
+
+    /// Get a generic element by ARIA state and optional accessible name.
+    ///
+    /// Some [`AriaState`] can be so descriptive in subsections of the DOM that an accessible name is
+    /// not required to identify a single element, such is the case with [`AriaState::Selected`] on a
+    /// subsection that can only have a single selected item. When the accessible name is not required
+    /// the value can be [`None`].
+    ///
+    /// Using an explicit element type as `T` will essentially skip the other types of elements - if
+    /// you want to find the very first element that matches the ARIA state and accessible name then
+    /// use [`HtmlElement`](web_sys::HtmlElement).
+    ///
+    /// Unlike [`get_by_aria_role`](ByAria::get_by_aria_role), this query intentionally includes
+    /// elements that are hidden from the user, so [`AriaState::Hidden`] can be used to assert that
+    /// an element has been hidden.
+    ///
+    /// # Panics
+    ///
+    /// _Nothing to see here._
+    ///
+    /// # Examples
+    ///
+    /// ## Get input with invalid spelling
+    ///
+    /// One input element has a misspelled animal value which has been validated and the
+    /// "aria-invalid=spelling" state has been applied.
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <form>
+    /// <input id="best-pet" aria-invalid="spelling" value="doge" aria-label="best pet" />
+    /// <input id="second-best-pet" value="cat" aria-label="second best pet" />
+    /// </form>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_invalid_spelling_input() {
+    /// let rendered: QueryElement = // feature dependent rendering
+    /// # QueryElement::new();
+    ///
+    /// let spelling_error_input: HtmlInputElement = rendered
+    /// .get_by_aria_state(AriaState::Invalid(InvalidToken::Spelling), "best pet")
+    /// .expect("expect to find input by spelling error - doge is a typo!");
+    ///
+    /// assert_eq!("best-pet", spelling_error_input.id());
+    /// }
+    /// ```
+    fn get_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> Result<T, Error>
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of [`get_by_aria_state`](ByAria::get_by_aria_state).
+    fn assert_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> T
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast;
+}
+
+/// True when the element is not rendered because it is part of the content of a closed `details`
+/// element - the first `summary` child of `details` is always rendered.
+fn is_in_closed_details(element: &Element) -> bool {
+    let mut child = element.clone();
+    while let Some(parent) = child.parent_element() {
+        if parent.tag_name().eq_ignore_ascii_case("details") && !parent.has_attribute("open") {
+            let is_summary = parent
+                .query_selector(":scope > summary")
+                .ok()
+                .flatten()
+                .map(|summary| summary == child)
+                .unwrap_or_default();
+            if !is_summary {
+                return true;
+            }
+        }
+        child = parent;
+    }
+    false
+}
+
+/// An element is removed from the accessibility tree when it, or any ancestor, has
+/// `aria-hidden="true"` or is not displayed.
+fn is_in_hidden_subtree(element: &Element) -> bool {
+    let window = web_sys::window();
+    let mut current = Some(element.clone());
+    while let Some(element) = current {
+        if element.get_attribute("aria-hidden").as_deref() == Some("true") {
+            return true;
+        }
+        let display_none = window
+            .as_ref()
+            .and_then(|w| w.get_computed_style(&element).ok().flatten())
+            .and_then(|style| style.get_property_value("display").ok())
+            .map(|display| display == "none")
+            .unwrap_or_default();
+        if display_none {
+            return true;
+        }
+        current = element.parent_element();
+    }
+    false
+}
+
+#[inline]
+pub(crate) fn is_hidden(element: &Element) -> bool {
+    is_in_closed_details(element) || is_in_hidden_subtree(element)
+}
+
+#[inline]
+fn get_by_aria_impl<S, T>(
+    root: &Element,
+    aria: S,
+    filter: Option<String>,
+    name: Option<&str>,
+    options: &MatchOptions,
+) -> Result<T, Error>
+where
+    S: ToQueryString,
+    T: JsCast,
+{
+    get_by_aria_named_impl(root, aria, filter, name, options).map(|(element, _)| element)
+}
+
+/// Same as [`get_by_aria_impl`] but includes the accessible name computed for the element, which
+/// is only computed when a `name` is given.
+fn get_by_aria_named_impl<S, T>(
+    root: &Element,
+    aria: S,
+    filter: Option<String>,
+    name: Option<&str>,
+    options: &MatchOptions,
+) -> Result<(T, Option<String>), Error>
+where
+    S: ToQueryString,
+    T: JsCast,
+{
+    let node_list = root.query_selector_all(&aria.to_query_string()).ok();
+    let include_hidden = options.include_hidden;
+    let focusable_only = options.focusable_only;
+    let enabled_only = options.enabled_only;
+    // the selectors of a role and a state or property can't always be combined into one, so
+    // the elements found by the role are checked against the filter selector instead
+    let mut node_iter = RawNodeListIter::<T>::new(node_list)
+        .filter(|element| include_hidden || !is_hidden(element.unchecked_ref()))
+        .filter(|element| !focusable_only || is_keyboard_focusable(element.unchecked_ref()))
+        .filter(|element| !enabled_only || !is_disabled(element.unchecked_ref()))
+        .filter(|element| {
+            filter.as_ref().map_or(true, |filter| {
+                element
+                    .unchecked_ref::<Element>()
+                    .matches(filter)
+                    .unwrap_or_default()
+            })
+        });
+    if let Some(name) = name {
+        let include_roledescription = options.include_roledescription;
+        let elements = node_iter.flat_map(|element| {
+            let roledescription = element
+                .unchecked_ref::<Element>()
+                .get_attribute("aria-roledescription")
+                .filter(|_| include_roledescription)
+                .map(|roledescription| {
+                    let element = element.unchecked_ref::<JsValue>().clone().unchecked_into();
+                    (roledescription.trim().to_owned(), element)
+                });
+            element_accessible_name(element.unchecked_ref())
+                .ok()
+                .map(|name| (name, element))
+                .into_iter()
+                .chain(roledescription)
+        });
+
+        if let Some((an, e)) = hyphae_utils::closest(name, elements, |(k, _)| k) {
+            if an == name {
+                Ok((e, Some(an)))
+            } else {
+                Err(Box::new(ByAriaError::Closest {
+                    name: name.to_owned(),
+                    inner_html: root.inner_html(),
+                    closest_node: e.unchecked_into(),
+                }))
+            }
+        } else {
+            Err(Box::new(ByAriaError::NotFound {
+                name: Some(name.to_owned()),
+                inner_html: root.inner_html(),
+            }))
+        }
+    } else if let Some(element) = node_iter.next() {
+        Ok((element, None))
+    } else {
+        Err(Box::new(ByAriaError::NotFound {
+            name: None,
+            inner_html: root.inner_html(),
+        }))
+    }
+}
+
+/// Composite widgets that disable all of their descendants with `aria-disabled="true"`.
+const DISABLED_COMPOSITE: &str = "[role=grid][aria-disabled=true], \
+    [role=listbox][aria-disabled=true], [role=menu][aria-disabled=true], \
+    [role=menubar][aria-disabled=true], [role=radiogroup][aria-disabled=true], \
+    [role=tablist][aria-disabled=true], [role=toolbar][aria-disabled=true], \
+    [role=tree][aria-disabled=true], [role=treegrid][aria-disabled=true]";
+
+#[inline]
+fn is_disabled(element: &Element) -> bool {
+    element.get_attribute("aria-disabled").as_deref() == Some("true")
+        || element.matches(":disabled").unwrap_or_default()
+        || element.closest(DISABLED_COMPOSITE).ok().flatten().is_some()
+}
+
+/// Whether the element can be reached with the `Tab` key.
+///
+/// The `tabIndex` of an element is `-1` when it has `tabindex="-1"` or is not focusable by
+/// default, however, disabled form controls keep the `tabIndex` of an enabled form control.
+#[inline]
+fn is_keyboard_focusable(element: &Element) -> bool {
+    element
+        .dyn_ref::<HtmlElement>()
+        .map_or(false, |element| element.tab_index() >= 0)
+        && !is_disabled(element)
+}
+
+/// Finds the first enabled element with the ARIA role and an accessible name equal to `name`,
+/// with an error that says the element is disabled when only disabled elements match.
+fn get_enabled_by_aria_role_impl<T>(
+    root: &Element,
+    role: AriaRole,
+    kind: &'static str,
+    name: &str,
+) -> Result<T, Error>
+where
+    T: JsCast,
+{
+    let node_list = root.query_selector_all(&role.to_query_string()).ok();
+    let (enabled, disabled): (Vec<T>, Vec<T>) = RawNodeListIter::<T>::new(node_list)
+        .filter(|element| !is_hidden(element.unchecked_ref()))
+        .filter(|element| {
+            element_accessible_name(element.unchecked_ref())
+                .map(|an| an == name)
+                .unwrap_or_default()
+        })
+        .partition(|element| !is_disabled(element.unchecked_ref()));
+
+    if let Some(element) = enabled.into_iter().next() {
+        Ok(element)
+    } else if let Some(element) = disabled.into_iter().next() {
+        Err(Box::new(ByAriaError::Disabled {
+            kind,
+            name: name.to_owned(),
+            inner_html: root.inner_html(),
+            disabled_node: element.unchecked_into(),
+        }))
+    } else {
+        // use the role query for the not found or closest match error
+        get_by_aria_impl(root, role, None, Some(name), &MatchOptions::default())
+    }
+}
+
+/// The number of lists that the element is nested in.
+fn list_nesting_level(element: &Element) -> usize {
+    let list = AriaRole::List.to_query_string();
+    std::iter::successors(element.parent_element(), |e| e.parent_element())
+        .filter(|ancestor| ancestor.matches(&list).unwrap_or_default())
+        .count()
+}
+
+/// Properties and states describe elements rather than finding what the user can interact with,
+/// so hidden elements are included.
+#[inline]
+fn include_hidden() -> MatchOptions {
+    MatchOptions {
+        include_hidden: true,
+        ..Default::default()
+    }
+}
+
+impl ByAria for QueryElement {
+    fn assert_by_aria_role<T>(&self, role: AriaRole, name: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_aria_role(role, name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_role<T>(&self, role: AriaRole, name: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        self.get_by_aria_role_with_options(role, name, MatchOptions::default())
+    }
+
+    fn assert_by_aria_role_with_options<T>(
+        &self,
+        role: AriaRole,
+        name: &str,
+        options: MatchOptions,
+    ) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_aria_role_with_options(role, name, options);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_role_with_options<T>(
+        &self,
+        role: AriaRole,
+        name: &str,
+        options: MatchOptions,
+    ) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        get_by_aria_impl(self, role, None, name.into(), &options)
+    }
+
+    fn assert_by_aria_role_where<T, F>(&self, role: AriaRole, predicate: F) -> T
+    where
+        T: JsCast,
+        F: Fn(&HtmlElement) -> bool,
+    {
+        let result = self.get_by_aria_role_where(role, predicate);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_role_where<T, F>(&self, role: AriaRole, predicate: F) -> Result<T, Error>
+    where
+        T: JsCast,
+        F: Fn(&HtmlElement) -> bool,
+    {
+        let node_list = self.query_selector_all(&role.to_query_string()).ok();
+        RawNodeListIter::<T>::new(node_list)
+            .filter(|element| !is_hidden(element.unchecked_ref()))
+            .find(|element| predicate(element.unchecked_ref()))
+            .ok_or_else(|| {
+                Box::new(ByAriaError::NoPredicateMatch {
+                    role,
+                    inner_html: self.inner_html(),
+                }) as Error
+            })
+    }
+
+    fn get_by_aria_role_named<T>(&self, role: AriaRole, name: &str) -> Result<(T, String), Error>
+    where
+        T: JsCast,
+    {
+        get_by_aria_named_impl(self, role, None, Some(name), &MatchOptions::default())
+            .map(|(element, an)| (element, an.unwrap_or_default()))
+    }
+
+    fn get_all_by_aria_role_named<T>(&self, role: AriaRole, name: &str) -> ElementIter<T>
+    where
+        T: JsCast,
+    {
+        let node_list = self.query_selector_all(&role.to_query_string()).ok();
+        let name = name.to_owned();
+        let elements = RawNodeListIter::<T>::new(node_list)
+            .filter(|element| !is_hidden(element.unchecked_ref()))
+            .filter(move |element| {
+                element_accessible_name(element.unchecked_ref())
+                    .ok()
+                    .as_ref()
+                    == Some(&name)
+            });
+        ElementIter::from_elements(elements)
+    }
+
+    fn assert_by_aria_role_labelled<T>(&self, role: AriaRole, aria_label: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_aria_role_labelled(role, aria_label);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_role_labelled<T>(&self, role: AriaRole, aria_label: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let node_list = self.query_selector_all(&role.to_query_string()).ok();
+        let elements = RawNodeListIter::<T>::new(node_list)
+            .filter(|element| !is_hidden(element.unchecked_ref()))
+            .filter_map(|element| {
+                let label = element
+                    .unchecked_ref::<Element>()
+                    .get_attribute("aria-label")?;
+                Some((label.trim().to_owned(), element))
+            });
+
+        if let Some((label, e)) = hyphae_utils::closest(aria_label, elements, |(k, _)| k) {
+            if label == aria_label {
+                Ok(e)
+            } else {
+                Err(Box::new(ByAriaError::Closest {
+                    name: aria_label.to_owned(),
+                    inner_html: self.inner_html(),
+                    closest_node: e.unchecked_into(),
+                }))
+            }
+        } else {
+            Err(Box::new(ByAriaError::NotFound {
+                name: Some(aria_label.to_owned()),
+                inner_html: self.inner_html(),
+            }))
+        }
+    }
+
+    fn get_menu_item<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        get_enabled_by_aria_role_impl(self, AriaRole::MenuItem, "menu item", name)
+    }
+
+    fn select_listbox_option<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let option = get_enabled_by_aria_role_impl::<T>(self, AriaRole::Option, "option", name)?;
+        option.unchecked_ref::<HtmlElement>().click();
+        Ok(option)
+    }
+
+    fn assert_accessible(&self, role: AriaRole, name: &str) {
+        self.assert_by_aria_role::<Element>(role, name);
+    }
+
+    fn assert_no_role(&self, role: AriaRole, name: &str) {
+        if let Ok(element) = self.get_by_aria_role::<Element>(role, name) {
+            self.pretty_panic(&format!(
+                "Expected no element with the role {:?} and the accessible name '{}' but found:\n{}",
+                role,
+                name,
+                element.outer_html()
+            ));
+        }
+    }
+
+    fn assert_meter_value(&self, name: &str) -> f64 {
+        let result = self.get_meter_value(name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_meter_value(&self, name: &str) -> Result<f64, Error> {
+        let meter: Element = self.get_by_aria_role(AriaRole::Meter, name)?;
+        let value_now = meter
+            .get_attribute("aria-valuenow")
+            .and_then(|value| value.trim().parse().ok());
+        value_now
+            .or_else(|| {
+                meter
+                    .dyn_ref::<HtmlMeterElement>()
+                    .map(|meter| meter.value())
+            })
+            .ok_or_else(|| {
+                Box::new(ByAriaError::NoValue {
+                    name: name.to_owned(),
+                    inner_html: self.inner_html(),
+                    node: meter.unchecked_into(),
+                }) as Error
+            })
+    }
+
+    fn assert_list_item<T>(&self, name: &str, level: usize) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_list_item(name, level);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_list_item<T>(&self, name: &str, level: usize) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let node_list = self
+            .query_selector_all(&AriaRole::ListItem.to_query_string())
+            .ok();
+        let elements = RawNodeListIter::<T>::new(node_list)
+            .filter(|element| !is_hidden(element.unchecked_ref()))
+            .filter(|element| list_nesting_level(element.unchecked_ref()) == level)
+            .filter_map(|element| {
+                Some((
+                    element_accessible_name(element.unchecked_ref()).ok()?,
+                    element,
+                ))
+            });
+
+        if let Some((an, e)) = hyphae_utils::closest(name, elements, |(k, _)| k) {
+            if an == name {
+                Ok(e)
+            } else {
+                Err(Box::new(ByAriaError::Closest {
+                    name: name.to_owned(),
+                    inner_html: self.inner_html(),
+                    closest_node: e.unchecked_into(),
+                }))
+            }
+        } else {
+            Err(Box::new(ByAriaError::NotFound {
+                name: Some(name.to_owned()),
+                inner_html: self.inner_html(),
+            }))
+        }
+    }
+
+    fn assert_by_name<T>(&self, name: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_name(name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_name<T>(&self, name: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let node_list = self.query_selector_all("*").ok();
+        let named: Vec<(String, T)> = RawNodeListIter::<T>::new(node_list)
+            .filter(|element| !is_hidden(element.unchecked_ref()))
+            .filter_map(|element| {
+                Some((
+                    element_accessible_name(element.unchecked_ref()).ok()?,
+                    element,
+                ))
+            })
+            .filter(|(an, _)| !an.is_empty())
+            .collect();
+
+        // elements are in document order so the descendants of a match come straight after it
+        let mut found: Option<&Node> = None;
+        for (_, element) in named.iter().filter(|(an, _)| an == name) {
+            let element = element.unchecked_ref::<Node>();
+            match found {
+                Some(outer) if !outer.contains(Some(element)) => break,
+                _ => found = Some(element),
+            }
+        }
+
+        if let Some(found) = found {
+            Ok(found.clone().unchecked_into())
+        } else if let Some((_, e)) = hyphae_utils::closest(name, named.into_iter(), |(k, _)| k) {
+            Err(Box::new(ByAriaError::Closest {
+                name: name.to_owned(),
+                inner_html: self.inner_html(),
+                closest_node: e.unchecked_into(),
+            }))
+        } else {
+            Err(Box::new(ByAriaError::NotFound {
+                name: Some(name.to_owned()),
+                inner_html: self.inner_html(),
+            }))
+        }
+    }
+
+    fn assert_by_aria_role_with_state<T>(&self, role: AriaRole, state: AriaState, name: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_aria_role_with_state(role, state, name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_role_with_state<T>(
+        &self,
+        role: AriaRole,
+        state: AriaState,
+        name: &str,
+    ) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        get_by_aria_impl(
+            self,
+            role,
+            Some(state.to_query_string()),
+            name.into(),
+            &MatchOptions::default(),
+        )
+    }
+
+    fn assert_by_aria_role_with_prop<T>(
+        &self,
+        role: AriaRole,
+        property: AriaProperty,
+        name: &str,
+    ) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_aria_role_with_prop(role, property, name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_role_with_prop<T>(
+        &self,
+        role: AriaRole,
+        property: AriaProperty,
+        name: &str,
+    ) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        get_by_aria_impl(
+            self,
+            role,
+            Some(property.to_query_string()),
+            name.into(),
+            &MatchOptions::default(),
+        )
+    }
+
+    fn assert_by_aria_prop<'name, S, T>(&self, property: AriaProperty, name: S) -> T
+    where
+        S: Into<Option<&'name str>>,
         T: JsCast,
     {
         let result = self.get_by_aria_prop(property, name);
@@ -514,275 +1711,954 @@ impl ByAria for QueryElement {
         result.unwrap()
     }
 
-    fn get_by_aria_prop<'name, S, T>(&self, prop: AriaProperty, name: S) -> Result<T, Error>
-    where
-        S: Into<Option<&'name str>>,
-        T: JsCast,
-    {
-        get_by_aria_impl(self, prop, name.into())
+    fn get_by_aria_prop<'name, S, T>(&self, prop: AriaProperty, name: S) -> Result<T, Error>
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast,
+    {
+        get_by_aria_impl(self, prop, None, name.into(), &include_hidden())
+    }
+
+    fn assert_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> T
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast,
+    {
+        let result = self.get_by_aria_state(state, name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> Result<T, Error>
+    where
+        S: Into<Option<&'name str>>,
+        T: JsCast,
+    {
+        get_by_aria_impl(self, state, None, name.into(), &include_hidden())
+    }
+}
+
+/// An error indicating that no element with an accessible name was an equal match for a given search term.
+enum ByAriaError {
+    /// No element could be found with the given search term.
+    NotFound {
+        name: Option<String>,
+        inner_html: String,
+    },
+    /// The only elements with the accessible name are disabled.
+    Disabled {
+        kind: &'static str,
+        name: String,
+        inner_html: String,
+        disabled_node: Node,
+    },
+    /// No element with the ARIA role passed the predicate given.
+    NoPredicateMatch { role: AriaRole, inner_html: String },
+    /// The element with the accessible name has no value to read.
+    NoValue {
+        name: String,
+        inner_html: String,
+        node: Node,
+    },
+    /// No element accessible name was an exact match for the search term could be found, however, an
+    /// element with a similar accessible name as the search term was found.
+    ///
+    /// This should help find elements when a user has made a typo in either the test or the
+    /// implementation being tested or when trying to find text with a dynamic number that may be
+    /// incorrect
+    Closest {
+        name: String,
+        inner_html: String,
+        closest_node: Node,
+    },
+}
+
+impl Debug for ByAriaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ByAriaError::NotFound {
+                name: None,
+                inner_html,
+            } => {
+                write!(
+                    f,
+                    "\nNo element found with the aria type provided in the following HTML:{}. \
+                    Is the element you are searching for match the ARIA type and generic type \
+                    provided?
+                    Note: ARIA type variants comments provide information on which element, \
+                    properties or state they match.",
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
+            ByAriaError::NotFound {
+                name: Some(name),
+                inner_html,
+            } => {
+                write!(
+                    f,
+                    "\nNo element found with an accessible name equal or similar to '{}' in the following HTML:{}",
+                    name,
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
+            ByAriaError::Disabled {
+                kind,
+                name,
+                inner_html,
+                disabled_node,
+            } => {
+                write!(
+                    f,
+                    "\nThe {} '{}' is disabled in the following HTML:{}",
+                    kind,
+                    name,
+                    hyphae_utils::format_html_with_closest(
+                        inner_html,
+                        disabled_node.unchecked_ref()
+                    )
+                )
+            }
+            ByAriaError::NoPredicateMatch { role, inner_html } => {
+                write!(
+                    f,
+                    "\nNo element with the ARIA role {:?} matched the predicate in the following HTML:{}",
+                    role,
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
+            ByAriaError::NoValue {
+                name,
+                inner_html,
+                node,
+            } => {
+                write!(
+                    f,
+                    "\nThe element named '{}' has no aria-valuenow or native value in the following HTML:{}",
+                    name,
+                    hyphae_utils::format_html_with_closest(inner_html, node.unchecked_ref())
+                )
+            }
+            ByAriaError::Closest {
+                name,
+                inner_html,
+                closest_node,
+            } => {
+                write!(
+                    f,
+                    "\nNo exact match found for an accessible name of: '{}'.\nA similar match was found in the following HTML:{}",
+                    name,
+                    hyphae_utils::format_html_with_closest(inner_html, closest_node.unchecked_ref())
+                )
+            }
+        }
+    }
+}
+
+impl Display for ByAriaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ByAriaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae_aria::{
+        property::OrientationToken,
+        state::{DuoState, InvalidToken},
+    };
+    use hyphae_utils::make_element_with_html_string;
+
+    use web_sys::{
+        HtmlButtonElement, HtmlImageElement, HtmlInputElement, ShadowRootInit, ShadowRootMode,
+    };
+
+    #[wasm_bindgen_test]
+    fn get_by_button_role_with_text_content() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <div id="not-mybtn">
+                    click me
+                <button id="mybtn">click me!</button>
+                </div>
+            </div>
+        "#,
+        )
+        .into();
+        let button: HtmlButtonElement = rendered
+            .get_by_aria_role(AriaRole::Button, "click me!")
+            .unwrap();
+
+        assert_eq!("mybtn", button.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_by_aria_label() {
+        // No text content in button
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <div id="not-mybtn">
+                    <button id="mybtn" aria-label="ok" />
+                </div>
+            </div>
+        "#,
+        )
+        .into();
+
+        let button: HtmlButtonElement = rendered
+            .get_by_aria_prop(AriaProperty::Label("ok".to_owned()), None)
+            .unwrap();
+
+        assert_eq!("mybtn", button.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_by_aria_disabled_state() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <input type="email" id="my-input" aria-disabled="true" />
+            </div>
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered
+            .get_by_aria_state(AriaState::Disabled(true), None)
+            .unwrap();
+
+        assert_eq!("my-input", input.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_single_input_with_spelling_error() {
+        let rendered: QueryElement = make_element_with_html_string(r#"
+            <form>
+                <input id="best-pet" aria-label="best pet" aria-invalid="spelling" value="doge" />
+                <input id="second-best-pet" aria-label="second best pet" aria-invalid="false" value="cat"  />
+            </form>
+        "#).into();
+        let spelling_error_input: HtmlInputElement = rendered
+            .get_by_aria_state(AriaState::Invalid(InvalidToken::Spelling), "best pet")
+            .unwrap();
+
+        assert_eq!("best-pet", spelling_error_input.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_aria_hidden_region_by_hidden_state() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="visible" aria-hidden="false">Visible</div>
+            <div id="region" role="region" aria-hidden="true">
+                <p>Background content</p>
+            </div>
+        "#,
+        )
+        .into();
+
+        let region: HtmlElement = rendered
+            .get_by_aria_state(AriaState::Hidden(DuoState::True), None)
+            .unwrap();
+        assert_eq!("region", region.id());
+
+        let visible: HtmlElement = rendered
+            .get_by_aria_state(AriaState::Hidden(DuoState::False), None)
+            .unwrap();
+        assert_eq!("visible", visible.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_element_with_hidden_attribute_by_hidden_state() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="shown">Shown</div>
+            <div id="hidden" hidden>Hidden</div>
+        "#,
+        )
+        .into();
+
+        let hidden: HtmlElement = rendered
+            .get_by_aria_state(AriaState::Hidden(DuoState::True), None)
+            .unwrap();
+        assert_eq!("hidden", hidden.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_input_by_role_with_aria_label() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <input id="myinput" type="text" aria-label="username" />
+            </div>
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered
+            .get_by_aria_role(AriaRole::TextBox, "username")
+            .unwrap();
+
+        assert_eq!("myinput", input.id());
     }
 
-    fn assert_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> T
-    where
-        S: Into<Option<&'name str>>,
-        T: JsCast,
-    {
-        let result = self.get_by_aria_state(state, name);
-        if result.is_err() {
-            self.remove();
-        }
-        result.unwrap()
+    #[wasm_bindgen_test]
+    fn get_button_by_role_with_aria_labelledby() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="button-label">
+                My custom button label
+            </div>
+            <button aria-labelledby="button-label" />
+        "#,
+        )
+        .into();
+
+        let button: HtmlButtonElement = rendered
+            .get_by_aria_role(AriaRole::Button, "My custom button label")
+            .unwrap();
+
+        assert_eq!(
+            "button-label",
+            button.get_attribute("aria-labelledby").unwrap()
+        );
     }
 
-    fn get_by_aria_state<'name, S, T>(&self, state: AriaState, name: S) -> Result<T, Error>
-    where
-        S: Into<Option<&'name str>>,
-        T: JsCast,
-    {
-        get_by_aria_impl(self, state, name.into())
+    #[wasm_bindgen_test]
+    fn get_input_by_role_with_label() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <div>
+                    <label for="my-input">My input label</label>
+                </div>
+                <input id="my-input" type="search" />
+            </div>
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered
+            .get_by_aria_role(AriaRole::Searchbox, "My input label")
+            .unwrap();
+
+        assert_eq!("my-input", input.id());
     }
-}
 
-/// An error indicating that no element with an accessible name was an equal match for a given search term.
-enum ByAriaError {
-    /// No element could be found with the given search term.
-    NotFound {
-        name: Option<String>,
-        inner_html: String,
-    },
-    /// No element accessible name was an exact match for the search term could be found, however, an
-    /// element with a similar accessible name as the search term was found.
-    ///
-    /// This should help find elements when a user has made a typo in either the test or the
-    /// implementation being tested or when trying to find text with a dynamic number that may be
-    /// incorrect
-    Closest {
-        name: String,
-        inner_html: String,
-        closest_node: Node,
-    },
-}
+    #[wasm_bindgen_test]
+    fn get_img_by_role_with_alt() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <img id="no" src="first-img.jpg" />
+                <img id="yes" src="somg-img.jpg" alt="The best image ever!" />
+            </div>
+        "#,
+        )
+        .into();
 
-impl Debug for ByAriaError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ByAriaError::NotFound {
-                name: None,
-                inner_html,
-            } => {
-                write!(
-                    f,
-                    "\nNo element found with the aria type provided in the following HTML:{}. \
-                    Is the element you are searching for match the ARIA type and generic type \
-                    provided?
-                    Note: ARIA type variants comments provide information on which element, \
-                    properties or state they match.",
-                    hyphae_utils::format_html(inner_html)
-                )
-            }
-            ByAriaError::NotFound {
-                name: Some(name),
-                inner_html,
-            } => {
-                write!(
-                    f,
-                    "\nNo element found with an accessible name equal or similar to '{}' in the following HTML:{}",
-                    name,
-                    hyphae_utils::format_html(inner_html)
-                )
-            }
-            ByAriaError::Closest {
-                name,
-                inner_html,
-                closest_node,
-            } => {
-                write!(
-                    f,
-                    "\nNo exact match found for an accessible name of: '{}'.\nA similar match was found in the following HTML:{}",
-                    name,
-                    hyphae_utils::format_html_with_closest(inner_html, closest_node.unchecked_ref())
-                )
-            }
-        }
+        let img: HtmlImageElement = rendered
+            .get_by_aria_role(AriaRole::Image, "The best image ever!")
+            .unwrap();
+
+        assert_eq!("yes", img.id());
     }
-}
 
-impl Display for ByAriaError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{:?}", self)
+    #[wasm_bindgen_test]
+    fn anchor_without_href_is_not_a_link() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <a id="placeholder">Home</a>
+            <a id="home" href="/home">Home page</a>
+        "#,
+        )
+        .into();
+
+        // an `a` without `href` is generic and not a link
+        assert!(rendered
+            .get_by_aria_role::<HtmlElement>(AriaRole::Link, "Home")
+            .is_err());
+
+        let link: HtmlElement = rendered
+            .get_by_aria_role(AriaRole::Link, "Home page")
+            .unwrap();
+        assert_eq!("home", link.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn button_is_accessible() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button>Submit</button>
+        "#,
+        )
+        .into();
+
+        rendered.assert_accessible(AriaRole::Button, "Submit");
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic]
+    fn missing_button_is_not_accessible() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>Submit</div>
+        "#,
+        )
+        .into();
+
+        rendered.assert_accessible(AriaRole::Button, "Submit");
+    }
+
+    #[wasm_bindgen_test]
+    fn no_role_when_element_is_absent() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button>Save</button>
+            <div>Delete</div>
+        "#,
+        )
+        .into();
+
+        rendered.assert_no_role(AriaRole::Button, "Delete");
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(
+        expected = "Expected no element with the role Button and the accessible name 'Delete' but found:\n<button id=\"delete\">Delete</button>"
+    )]
+    fn no_role_reports_unexpected_element() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button>Save</button>
+            <button id="delete">Delete</button>
+        "#,
+        )
+        .into();
+
+        rendered.assert_no_role(AriaRole::Button, "Delete");
+    }
+
+    #[wasm_bindgen_test]
+    fn get_expanded_combobox_by_role_with_state() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input id="collapsed" role="combobox" aria-label="city" aria-expanded="false" />
+            <input id="expanded" role="combobox" aria-label="city" aria-expanded="true" />
+        "#,
+        )
+        .into();
+
+        let combobox: HtmlInputElement = rendered
+            .get_by_aria_role_with_state(
+                AriaRole::Combobox,
+                AriaState::Expanded(DuoState::True),
+                "city",
+            )
+            .unwrap();
+        assert_eq!("expanded", combobox.id());
+
+        let combobox: HtmlInputElement = rendered
+            .get_by_aria_role_with_state(
+                AriaRole::Combobox,
+                AriaState::Expanded(DuoState::False),
+                "city",
+            )
+            .unwrap();
+        assert_eq!("collapsed", combobox.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_vertical_slider_by_role_with_prop() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="saturation" role="slider" aria-label="colour" aria-orientation="horizontal"></div>
+            <div id="lightness" role="slider" aria-label="colour" aria-orientation="vertical"></div>
+        "#,
+        )
+        .into();
+
+        let slider: HtmlElement = rendered.assert_by_aria_role_with_prop(
+            AriaRole::Slider,
+            AriaProperty::Orientation(OrientationToken::Vertical),
+            "colour",
+        );
+        assert_eq!("lightness", slider.id());
+
+        let slider: HtmlElement = rendered.assert_by_aria_role_with_prop(
+            AriaRole::Slider,
+            AriaProperty::Orientation(OrientationToken::Horizontal),
+            "colour",
+        );
+        assert_eq!("saturation", slider.id());
+
+        assert!(rendered
+            .get_by_aria_role_with_prop::<HtmlElement>(
+                AriaRole::Slider,
+                AriaProperty::Orientation(OrientationToken::Undefined),
+                "colour",
+            )
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn only_top_level_header_and_footer_are_landmarks() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <header id="site-header" aria-label="Site">Logo</header>
+            <main>
+                <article>
+                    <header aria-label="Post">Title</header>
+                    <footer aria-label="Post details">Posted today</footer>
+                </article>
+            </main>
+            <footer id="site-footer" aria-label="Site details">Copyright</footer>
+        "#,
+        )
+        .into();
+
+        let banner: HtmlElement = rendered.assert_by_aria_role(AriaRole::Banner, "Site");
+        assert_eq!("site-header", banner.id());
+        assert!(rendered
+            .get_by_aria_role::<HtmlElement>(AriaRole::Banner, "Post")
+            .is_err());
+
+        let contentinfo: HtmlElement =
+            rendered.assert_by_aria_role(AriaRole::ContentInfo, "Site details");
+        assert_eq!("site-footer", contentinfo.id());
+        assert!(rendered
+            .get_by_aria_role::<HtmlElement>(AriaRole::ContentInfo, "Post details")
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_meter_value_of_native_and_aria_meter() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <label for="disk">Disk usage</label>
+            <meter id="disk" min="0" max="100" value="72"></meter>
+            <div role="meter" aria-label="Battery" aria-valuenow="40" aria-valuemin="0" aria-valuemax="100"></div>
+            <div role="meter" aria-label="Signal"></div>
+        "#,
+        )
+        .into();
+
+        let disk: HtmlElement = rendered.assert_by_aria_role(AriaRole::Meter, "Disk usage");
+        assert_eq!("disk", disk.id());
+        assert_eq!(72.0, rendered.assert_meter_value("Disk usage"));
+        assert_eq!(40.0, rendered.assert_meter_value("Battery"));
+
+        let error = rendered.get_meter_value("Signal").unwrap_err();
+        assert!(format!("{:?}", error)
+            .starts_with("\nThe element named 'Signal' has no aria-valuenow or native value"));
+    }
+
+    #[wasm_bindgen_test]
+    fn get_figure_by_figcaption() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <figure id="sales">
+                <img src="sales.png" alt="Bar chart of sales" />
+                <figcaption>Sales by quarter</figcaption>
+            </figure>
+            <figure id="costs">
+                <figcaption>Costs by quarter</figcaption>
+                <img src="costs.png" alt="Bar chart of costs" />
+            </figure>
+        "#,
+        )
+        .into();
+
+        let figure: HtmlElement =
+            rendered.assert_by_aria_role(AriaRole::Figure, "Sales by quarter");
+        assert_eq!("sales", figure.id());
+
+        let figure: HtmlElement =
+            rendered.assert_by_aria_role(AriaRole::Figure, "Costs by quarter");
+        assert_eq!("costs", figure.id());
     }
-}
 
-impl std::error::Error for ByAriaError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(self)
+    #[wasm_bindgen_test]
+    fn get_table_by_caption() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <table id="invoices">
+                <caption>Invoices</caption>
+                <tr><th>Number</th><th>Amount</th></tr>
+                <tr><td>1</td><td>£10</td></tr>
+            </table>
+            <table id="payments">
+                <caption>Payments</caption>
+                <tr><th>Number</th><th>Amount</th></tr>
+            </table>
+        "#,
+        )
+        .into();
+
+        let table: HtmlElement = rendered.assert_by_aria_role(AriaRole::Table, "Payments");
+        assert_eq!("payments", table.id());
+
+        assert!(rendered
+            .get_by_aria_role::<HtmlElement>(AriaRole::Table, "Number")
+            .is_err());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[wasm_bindgen_test]
+    fn get_button_named_by_multiple_labelledby_refs() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li>
+                    <a id="file_row1" href="./files/Documentation.pdf">Documentation.pdf</a>
+                    <span role="button" tabindex="0" id="del_row1" aria-label="Delete" aria-labelledby="del_row1 file_row1"></span>
+                </li>
+                <li>
+                    <a id="file_row2" href="./files/Notes.pdf">Notes.pdf</a>
+                    <span role="button" tabindex="0" id="del_row2" aria-label="Delete" aria-labelledby="del_row2 file_row2"></span>
+                </li>
+            </ul>
+        "#,
+        )
+        .into();
 
-    use wasm_bindgen_test::*;
-    wasm_bindgen_test_configure!(run_in_browser);
+        let button: HtmlElement =
+            rendered.assert_by_aria_role(AriaRole::Button, "Delete Documentation.pdf");
+        assert_eq!("del_row1", button.id());
 
-    use hyphae_aria::state::InvalidToken;
-    use hyphae_utils::make_element_with_html_string;
+        let button: HtmlElement =
+            rendered.assert_by_aria_role(AriaRole::Button, "Delete Notes.pdf");
+        assert_eq!("del_row2", button.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_all_buttons_named_remove() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li>Milk <button id="remove-milk">Remove</button> <button>Edit</button></li>
+                <li>Eggs <button id="remove-eggs">Remove</button> <button>Edit</button></li>
+                <li>Flour <button id="remove-flour">Remove</button></li>
+            </ul>
+            <a href="/remove">Remove</a>
+        "#,
+        )
+        .into();
 
-    use web_sys::{HtmlButtonElement, HtmlImageElement, HtmlInputElement};
+        let ids: Vec<String> = rendered
+            .get_all_by_aria_role_named::<HtmlButtonElement>(AriaRole::Button, "Remove")
+            .map(|button| button.id())
+            .collect();
+        assert_eq!(vec!["remove-milk", "remove-eggs", "remove-flour"], ids);
+
+        assert_eq!(
+            2,
+            rendered
+                .get_all_by_aria_role_named::<HtmlButtonElement>(AriaRole::Button, "Edit")
+                .count()
+        );
+        assert_eq!(
+            0,
+            rendered
+                .get_all_by_aria_role_named::<HtmlButtonElement>(AriaRole::Button, "Remov")
+                .count()
+        );
+    }
 
     #[wasm_bindgen_test]
-    fn get_by_button_role_with_text_content() {
+    fn get_icon_button_by_roledescription() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div>
-                <div id="not-mybtn">
-                    click me
-                <button id="mybtn">click me!</button>
-                </div>
-            </div>
+            <button id="download" aria-roledescription="Download"><svg width="16" height="16"></svg></button>
+            <button id="share">Share</button>
         "#,
         )
         .into();
+
+        assert!(rendered
+            .get_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Download")
+            .is_err());
+
+        let options = MatchOptions {
+            include_roledescription: true,
+            ..Default::default()
+        };
         let button: HtmlButtonElement = rendered
-            .get_by_aria_role(AriaRole::Button, "click me!")
+            .get_by_aria_role_with_options(AriaRole::Button, "Download", options.clone())
             .unwrap();
+        assert_eq!("download", button.id());
 
-        assert_eq!("mybtn", button.id());
+        // the accessible name is still matched
+        let button: HtmlButtonElement = rendered
+            .get_by_aria_role_with_options(AriaRole::Button, "Share", options)
+            .unwrap();
+        assert_eq!("share", button.id());
     }
 
     #[wasm_bindgen_test]
-    fn get_by_aria_label() {
-        // No text content in button
+    fn get_button_slotted_into_shadow_root() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div>
-                <div id="not-mybtn">
-                    <button id="mybtn" aria-label="ok" />
-                </div>
-            </div>
+            <div id="toolbar-host"><button id="save">Save</button></div>
         "#,
         )
         .into();
 
-        let button: HtmlButtonElement = rendered
-            .get_by_aria_prop(AriaProperty::Label("ok".to_owned()), None)
+        let host = rendered.query_selector("#toolbar-host").unwrap().unwrap();
+        let shadow = host
+            .attach_shadow(&ShadowRootInit::new(ShadowRootMode::Open))
             .unwrap();
+        shadow.set_inner_html(r#"<div role="toolbar" aria-label="Actions"><slot></slot></div>"#);
 
-        assert_eq!("mybtn", button.id());
+        let save: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+        assert_eq!("save", save.id());
+        let slot = save
+            .assigned_slot()
+            .expect("button should be assigned to the slot");
+        assert_eq!("SLOT", slot.tag_name());
     }
 
     #[wasm_bindgen_test]
-    fn get_by_aria_disabled_state() {
+    fn get_button_by_role_where_predicate() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div>
-                <input type="email" id="my-input" aria-disabled="true" />
-            </div>
+            <button id="cancel" data-variant="secondary">Cancel</button>
+            <div id="save" role="button" data-variant="primary">Save</div>
         "#,
         )
         .into();
 
-        let input: HtmlInputElement = rendered
-            .get_by_aria_state(AriaState::Disabled(true), None)
+        let button: HtmlElement = rendered
+            .get_by_aria_role_where(AriaRole::Button, |el| {
+                el.get_attribute("data-variant").as_deref() == Some("primary")
+            })
             .unwrap();
+        assert_eq!("save", button.id());
 
-        assert_eq!("my-input", input.id());
+        let err = rendered
+            .get_by_aria_role_where::<HtmlElement, _>(AriaRole::Button, |el| {
+                el.get_attribute("data-variant").as_deref() == Some("danger")
+            })
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("ARIA role Button matched the predicate"));
     }
 
     #[wasm_bindgen_test]
-    fn get_single_input_with_spelling_error() {
-        let rendered: QueryElement = make_element_with_html_string(r#"
-            <form>
-                <input id="best-pet" aria-label="best pet" aria-invalid="spelling" value="doge" />
-                <input id="second-best-pet" aria-label="second best pet" aria-invalid="false" value="cat"  />
-            </form>
-        "#).into();
-        let spelling_error_input: HtmlInputElement = rendered
-            .get_by_aria_state(AriaState::Invalid(InvalidToken::Spelling), "best pet")
+    fn get_document_structure_roles_by_implicit_elements() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <article>
+                <blockquote id="quote">Simplicity is prerequisite for reliability.</blockquote>
+                <p>Published <time id="published" datetime="1975-06-18">18 June 1975</time></p>
+            </article>
+        "#,
+        )
+        .into();
+
+        let quote: HtmlElement = rendered
+            .get_by_aria_role(
+                AriaRole::Blockquote,
+                "Simplicity is prerequisite for reliability.",
+            )
             .unwrap();
+        assert_eq!("quote", quote.id());
 
-        assert_eq!("best-pet", spelling_error_input.id());
+        let published: HtmlElement = rendered
+            .get_by_aria_role(AriaRole::Time, "18 June 1975")
+            .unwrap();
+        assert_eq!("published", published.id());
     }
 
     #[wasm_bindgen_test]
-    fn get_input_by_role_with_aria_label() {
+    fn get_by_aria_role_named_returns_accessible_name() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div>
-                <input id="myinput" type="text" aria-label="username" />
-            </div>
+            <button id="close" aria-label="Close dialog">X</button>
         "#,
         )
         .into();
 
-        let input: HtmlInputElement = rendered
-            .get_by_aria_role(AriaRole::TextBox, "username")
+        let (button, name): (HtmlButtonElement, String) = rendered
+            .get_by_aria_role_named(AriaRole::Button, "Close dialog")
             .unwrap();
+        assert_eq!("close", button.id());
+        assert_eq!("Close dialog", name);
+    }
 
-        assert_eq!("myinput", input.id());
+    #[wasm_bindgen_test]
+    fn disabled_listbox_option_is_reported_as_disabled() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul role="listbox" aria-label="Fruit">
+                <li role="option" aria-disabled="true">Apple</li>
+                <li id="banana" role="option">Banana</li>
+                <li id="banana-disabled" role="option" aria-disabled="true">Banana</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let banana: HtmlElement = rendered.select_listbox_option("Banana").unwrap();
+        assert_eq!("banana", banana.id());
+
+        let error = rendered
+            .select_listbox_option::<HtmlElement>("Apple")
+            .unwrap_err();
+        assert!(format!("{:?}", error).starts_with("\nThe option 'Apple' is disabled"));
     }
 
     #[wasm_bindgen_test]
-    fn get_button_by_role_with_aria_labelledby() {
+    fn get_menu_item_skips_disabled_items() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div id="button-label">
-                My custom button label
-            </div>
-            <button aria-labelledby="button-label" />
+            <ul role="menu">
+                <li role="menuitem" aria-disabled="true">Paste</li>
+                <li id="copy" role="menuitem">Copy</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let copy: HtmlElement = rendered.get_menu_item("Copy").unwrap();
+        assert_eq!("copy", copy.id());
+        assert!(rendered.get_menu_item::<HtmlElement>("Paste").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn closed_details_content_is_hidden_from_role_queries() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <details id="more">
+                <summary>More options</summary>
+                <button id="mybtn">Delete</button>
+            </details>
         "#,
         )
         .into();
 
+        assert!(rendered
+            .get_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Delete")
+            .is_err());
+
+        let options = MatchOptions {
+            include_hidden: true,
+            ..Default::default()
+        };
         let button: HtmlButtonElement = rendered
-            .get_by_aria_role(AriaRole::Button, "My custom button label")
+            .get_by_aria_role_with_options(AriaRole::Button, "Delete", options)
+            .unwrap();
+        assert_eq!("mybtn", button.id());
+
+        // summary is always rendered, even when details is closed
+        rendered.assert_by_aria_role::<HtmlElement>(AriaRole::Button, "More options");
+
+        rendered
+            .query_selector("#more")
+            .unwrap()
+            .unwrap()
+            .set_attribute("open", "")
             .unwrap();
 
-        assert_eq!(
-            "button-label",
-            button.get_attribute("aria-labelledby").unwrap()
-        );
+        let button: HtmlButtonElement = rendered
+            .get_by_aria_role(AriaRole::Button, "Delete")
+            .unwrap();
+        assert_eq!("mybtn", button.id());
     }
 
     #[wasm_bindgen_test]
-    fn get_input_by_role_with_label() {
+    fn aria_hidden_ancestor_hides_content_from_role_queries() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div>
-                <div>
-                    <label for="my-input">My input label</label>
-                </div>
-                <input id="my-input" type="search" />
+            <div aria-hidden="true">
+                <button id="hidden-btn">Delete</button>
+            </div>
+            <div style="display: none">
+                <button id="not-displayed-btn">Archive</button>
             </div>
         "#,
         )
         .into();
 
-        let input: HtmlInputElement = rendered
-            .get_by_aria_role(AriaRole::Searchbox, "My input label")
+        assert!(rendered
+            .get_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Delete")
+            .is_err());
+        assert!(rendered
+            .get_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Archive")
+            .is_err());
+
+        let options = MatchOptions {
+            include_hidden: true,
+            ..Default::default()
+        };
+        let button: HtmlButtonElement = rendered
+            .get_by_aria_role_with_options(AriaRole::Button, "Delete", options)
             .unwrap();
+        assert_eq!("hidden-btn", button.id());
+    }
 
-        assert_eq!("my-input", input.id());
+    #[wasm_bindgen_test]
+    fn explicit_role_overrides_implicit_role() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul role="tablist">
+                <li id="home-tab" role="tab">Home</li>
+            </ul>
+            <ul>
+                <li id="home-item">Home page</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let tab: HtmlElement = rendered.get_by_aria_role(AriaRole::Tab, "Home").unwrap();
+        assert_eq!("home-tab", tab.id());
+
+        assert!(rendered
+            .get_by_aria_role::<HtmlElement>(AriaRole::ListItem, "Home")
+            .is_err());
+
+        let item: HtmlElement = rendered
+            .get_by_aria_role(AriaRole::ListItem, "Home page")
+            .unwrap();
+        assert_eq!("home-item", item.id());
     }
 
     #[wasm_bindgen_test]
-    fn get_img_by_role_with_alt() {
+    fn get_presentational_elements_by_explicit_role() {
         let rendered: QueryElement = make_element_with_html_string(
             r#"
-            <div>
-                <img id="no" src="first-img.jpg" />
-                <img id="yes" src="somg-img.jpg" alt="The best image ever!" />
-            </div>
+            <div id="divider" role="presentation">Decoration</div>
+            <span id="spacer" role="none">Spacer</span>
+            <img id="decorative-img" src="flourish.png" alt="" title="Flourish" />
         "#,
         )
         .into();
 
-        let img: HtmlImageElement = rendered
-            .get_by_aria_role(AriaRole::Image, "The best image ever!")
+        let div: HtmlElement = rendered
+            .get_by_aria_role(AriaRole::Presentation, "Decoration")
             .unwrap();
+        assert_eq!("divider", div.id());
 
-        assert_eq!("yes", img.id());
+        let span: HtmlElement = rendered.get_by_aria_role(AriaRole::None, "Spacer").unwrap();
+        assert_eq!("spacer", span.id());
+
+        let img: HtmlImageElement = rendered
+            .get_by_aria_role(AriaRole::Presentation, "Flourish")
+            .unwrap();
+        assert_eq!("decorative-img", img.id());
     }
 
     #[wasm_bindgen_test]
@@ -841,4 +2717,185 @@ mod tests {
             }
         }
     }
+
+    #[wasm_bindgen_test]
+    fn get_by_name_without_role() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="toolbar">
+                <button id="save">Save</button>
+            </div>
+            <button id="saved">Saved</button>
+        "#,
+        )
+        .into();
+
+        let save: HtmlElement = rendered.assert_by_name("Save");
+        assert_eq!("save", save.id());
+
+        let result = rendered.get_by_name::<HtmlElement>("Sav");
+        assert!(result.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_list_item_by_nesting_level() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li>Fruit
+                    <ul>
+                        <li id="nested-apple">Apple</li>
+                    </ul>
+                </li>
+                <li id="apple">Apple</li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let nested: HtmlElement = rendered.assert_list_item("Apple", 2);
+        assert_eq!("nested-apple", nested.id());
+
+        let top: HtmlElement = rendered.assert_list_item("Apple", 1);
+        assert_eq!("apple", top.id());
+
+        assert!(rendered.get_list_item::<HtmlElement>("Apple", 3).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn focusable_only_skips_tabindex_minus_one() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button id="skipped" tabindex="-1">Close</button>
+            <button id="reachable">Close</button>
+        "#,
+        )
+        .into();
+
+        let first: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Close");
+        assert_eq!("skipped", first.id());
+
+        let options = MatchOptions {
+            focusable_only: true,
+            ..Default::default()
+        };
+        let reachable: HtmlButtonElement =
+            rendered.assert_by_aria_role_with_options(AriaRole::Button, "Close", options.clone());
+        assert_eq!("reachable", reachable.id());
+
+        reachable.set_disabled(true);
+        assert!(rendered
+            .get_by_aria_role_with_options::<HtmlButtonElement>(AriaRole::Button, "Close", options)
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn enabled_only_skips_buttons_in_disabled_toolbar() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div role="toolbar" aria-label="Formatting" aria-disabled="true">
+                <button id="toolbar-bold">Bold</button>
+            </div>
+            <button id="bold">Bold</button>
+        "#,
+        )
+        .into();
+
+        let first: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Bold");
+        assert_eq!("toolbar-bold", first.id());
+
+        let options = MatchOptions {
+            enabled_only: true,
+            ..Default::default()
+        };
+        let enabled: HtmlButtonElement =
+            rendered.assert_by_aria_role_with_options(AriaRole::Button, "Bold", options.clone());
+        assert_eq!("bold", enabled.id());
+
+        enabled.remove();
+        assert!(rendered
+            .get_by_aria_role_with_options::<HtmlButtonElement>(AriaRole::Button, "Bold", options)
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn expect_by_aria_role_with_question_mark() -> Result<(), Error> {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button id="submit">Submit</button>
+        "#,
+        )
+        .into();
+
+        let submit: HtmlButtonElement = rendered.expect_by_aria_role(AriaRole::Button, "Submit")?;
+        assert_eq!("submit", submit.id());
+        assert!(rendered
+            .expect_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Cancel")
+            .is_err());
+        Ok(())
+    }
+
+    #[wasm_bindgen_test]
+    fn custom_element_host_with_explicit_role() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <my-toolbar>
+                <my-button id="save" role="button">Save</my-button>
+                <my-button id="no-role">Save</my-button>
+            </my-toolbar>
+        "#,
+        )
+        .into();
+
+        let save: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+        assert_eq!("save", save.id());
+        assert_eq!("MY-BUTTON", save.tag_name());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_by_aria_role_labelled_ignores_descendant_text() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button id="text">Delete</button>
+            <button id="labelled" aria-label="Delete">
+                <span>X</span>
+            </button>
+        "#,
+        )
+        .into();
+
+        let by_name: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Delete");
+        assert_eq!("text", by_name.id());
+
+        let by_label: HtmlButtonElement =
+            rendered.assert_by_aria_role_labelled(AriaRole::Button, "Delete");
+        assert_eq!("labelled", by_label.id());
+
+        assert!(rendered
+            .get_by_aria_role_labelled::<HtmlButtonElement>(AriaRole::Button, "X")
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_slider_by_value_text() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="volume" role="slider" aria-label="Volume" aria-valuenow="50" aria-valuetext="50%"></div>
+            <div id="balance" role="slider" aria-label="Balance" aria-valuenow="0" aria-valuetext="Centre left"></div>
+        "#,
+        )
+        .into();
+
+        let volume: HtmlElement =
+            rendered.assert_by_aria_prop(AriaProperty::ValueText("50%".to_owned()), "Volume");
+        assert_eq!("volume", volume.id());
+        hyphae::assert_valuetext!("50%", volume);
+
+        let balance: HtmlElement = rendered
+            .assert_by_aria_prop(AriaProperty::ValueText("Centre left".to_owned()), "Balance");
+        assert_eq!("balance", balance.id());
+        let expected = format!("Centre {}", "left");
+        hyphae::assert_valuetext!(&expected, balance);
+        hyphae::assert_valuetext!(expected, balance);
+    }
 }
@@ -55,7 +55,7 @@ use std::fmt::{Debug, Display};
 use hyphae::{Error, QueryElement, RawNodeListIter};
 
 use wasm_bindgen::JsCast;
-use web_sys::Node;
+use web_sys::{Element, Node};
 
 /// Enables querying elements by `display value`.
 ///
@@ -193,6 +193,62 @@ pub trait ByDisplayValue {
     fn assert_by_display_value<T>(&self, search: &str) -> T
     where
         T: JsCast;
+
+    /// Get a generic element by display value, returning [`None`] when no element is found.
+    ///
+    /// Unlike [`assert_by_display_value`](ByDisplayValue::assert_by_display_value) the rendered
+    /// root element is not removed when no element is found, so this can be used to check that
+    /// no element shows a value and then continue the test.
+    fn query_by_display_value<T>(&self, search: &str) -> Option<T>
+    where
+        T: JsCast,
+    {
+        self.get_by_display_value(search).ok()
+    }
+
+    /// Get the checked radio or checkbox input with the given `value`.
+    ///
+    /// Unlike [`get_by_display_value`](ByDisplayValue::get_by_display_value) this only considers
+    /// `input[type=radio]` and `input[type=checkbox]` elements that are currently checked and the
+    /// `value` must be an exact match.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <fieldset>
+    ///     <input type="radio" name="shipping" value="standard" />
+    ///     <input type="radio" name="shipping" value="express" checked />
+    /// </fieldset>
+    /// ```
+    ///
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_checked_shipping_option() {
+    /// let rendered: QueryElement = // feature dependent rendering
+    /// # QueryElement::new();
+    /// let radio: HtmlInputElement = rendered
+    /// .get_checked_by_value("express")
+    /// .unwrap();
+    ///
+    /// assert!(radio.checked());
+    /// }
+    /// ```
+    fn get_checked_by_value<T>(&self, value: &str) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_checked_by_value`](ByDisplayValue::get_checked_by_value).
+    fn assert_checked_by_value<T>(&self, value: &str) -> T
+    where
+        T: JsCast;
 }
 
 impl ByDisplayValue for QueryElement {
@@ -234,6 +290,40 @@ impl ByDisplayValue for QueryElement {
             }))
         }
     }
+
+    fn assert_checked_by_value<T>(&self, value: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_checked_by_value(value);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_checked_by_value<T>(&self, value: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let elements = self
+            .query_selector_all("input[type=radio]:checked, input[type=checkbox]:checked")
+            .ok();
+
+        RawNodeListIter::<T>::new(elements)
+            .find(|element| {
+                element
+                    .unchecked_ref::<Element>()
+                    .get_attribute("value")
+                    .map_or(false, |v| v == value)
+            })
+            .ok_or_else(|| {
+                Box::new(ByDisplayValueError::NoneChecked {
+                    value: value.to_owned(),
+                    inner_html: self.inner_html(),
+                }) as Error
+            })
+    }
 }
 
 /// An error indicating that no element with a display value was an equal match for a given search term.
@@ -254,6 +344,8 @@ enum ByDisplayValueError {
         inner_html: String,
         closest_node: Node,
     },
+    /// No checked radio or checkbox input has the given value.
+    NoneChecked { value: String, inner_html: String },
 }
 
 impl Debug for ByDisplayValueError {
@@ -282,6 +374,14 @@ impl Debug for ByDisplayValueError {
                     hyphae_utils::format_html_with_closest(inner_html, closest_node.unchecked_ref()),
                 )
             }
+            ByDisplayValueError::NoneChecked { value, inner_html } => {
+                write!(
+                    f,
+                    "\nNo checked radio or checkbox found with the value '{}' in the following HTML:{}",
+                    value,
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
         }
     }
 }
@@ -305,7 +405,7 @@ mod tests {
     use wasm_bindgen_test::*;
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
-    use web_sys::{Element, HtmlInputElement, HtmlTextAreaElement};
+    use web_sys::{HtmlInputElement, HtmlTextAreaElement};
 
     use hyphae::QueryElement;
     use hyphae_utils::make_element_with_html_string;
@@ -323,6 +423,30 @@ mod tests {
         assert_eq!("greeting", input.id());
     }
 
+    #[wasm_bindgen_test]
+    fn assert_and_query_by_display_value() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input type="text" id="name" value="Gardening" />
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.assert_by_display_value("Gardening");
+        assert_eq!("name", input.id());
+
+        input.set_value("Cooking");
+        assert!(rendered
+            .query_by_display_value::<HtmlInputElement>("Gardening")
+            .is_none());
+        assert_eq!(
+            Some(input),
+            rendered.query_by_display_value::<HtmlInputElement>("Cooking")
+        );
+        // the root is not removed when nothing is found
+        assert!(rendered.is_connected());
+    }
+
     #[wasm_bindgen_test]
     fn get_text_area_due_to_type() {
         let rendered: QueryElement = make_element_with_html_string(
@@ -401,4 +525,34 @@ mod tests {
             }
         }
     }
+
+    #[wasm_bindgen_test]
+    fn get_checked_radio_by_value() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <fieldset>
+                <input type="radio" id="standard" name="shipping" value="standard" />
+                <input type="radio" id="express" name="shipping" value="express" checked />
+                <input type="radio" id="overnight" name="shipping" value="overnight" />
+            </fieldset>
+        "#,
+        )
+        .into();
+
+        let radio: HtmlInputElement = rendered.assert_checked_by_value("express");
+        assert_eq!("express", radio.id());
+
+        assert!(rendered
+            .get_checked_by_value::<HtmlInputElement>("standard")
+            .is_err());
+
+        let standard: HtmlInputElement = rendered.assert_by_display_value("standard");
+        standard.click();
+
+        let radio: HtmlInputElement = rendered.assert_checked_by_value("standard");
+        assert_eq!("standard", radio.id());
+        assert!(rendered
+            .get_checked_by_value::<HtmlInputElement>("express")
+            .is_err());
+    }
 }
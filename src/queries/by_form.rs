@@ -0,0 +1,250 @@
+//! Supports finding [`HtmlFormElement`]s by name and reading the values of their fields.
+//!
+//! # Form name
+//!
+//! The `name` attribute of a form element identifies the form amongst the other forms in the
+//! document:
+//!
+//! ```html
+//! <form name="login">
+//!             ^^^^^ the form name
+//!     <input name="username" type="text" />
+//! </form>
+//! ```
+//!
+//! # Form fields
+//!
+//! The fields of a form are the controls with a `name` attribute, the values of these fields are
+//! what would be submitted by the form. Use [`FormFields::field_values`] to read all of these values
+//! for an assertion.
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
+
+use hyphae::{Error, QueryElement, RawNodeListIter};
+
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlFormElement, HtmlInputElement};
+
+/// Enables querying forms by name.
+///
+/// _See each trait function for examples._
+pub trait ByForm {
+    /// Get a form element by the value of its `name` attribute.
+    ///
+    /// # Panics
+    /// _Nothing to see here._
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <form id="login-form" name="login">
+    ///     <input name="username" type="text" value="mc1098" />
+    /// </form>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlFormElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_login_form() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let form: HtmlFormElement = rendered
+    ///         .get_form_by_name("login")
+    ///         .expect("to find the login form");
+    ///
+    ///     assert_eq!("login-form", form.id());
+    /// }
+    /// ```
+    fn get_form_by_name(&self, name: &str) -> Result<HtmlFormElement, Error>;
+
+    /// A convenient method which unwraps the result of [`get_form_by_name`](ByForm::get_form_by_name).
+    fn assert_form_by_name(&self, name: &str) -> HtmlFormElement;
+}
+
+impl ByForm for QueryElement {
+    fn assert_form_by_name(&self, name: &str) -> HtmlFormElement {
+        let result = self.get_form_by_name(name);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_form_by_name(&self, name: &str) -> Result<HtmlFormElement, Error> {
+        let forms = self.query_selector_all("form").ok();
+        RawNodeListIter::<HtmlFormElement>::new(forms)
+            .find(|form| form.name() == name)
+            .ok_or_else(|| {
+                Box::new(ByFormError::NotFound {
+                    name: name.to_owned(),
+                    inner_html: self.inner_html(),
+                }) as Error
+            })
+    }
+}
+
+/// Enables reading the values of the fields of a form.
+pub trait FormFields {
+    /// Get the values of the named fields of this form, keyed by the `name` of each field.
+    ///
+    /// Only the values that would be submitted by the form are included, so the following are
+    /// skipped:
+    /// - fields without a `name` attribute
+    /// - disabled fields
+    /// - checkboxes and radios that are not checked
+    /// - buttons
+    ///
+    /// When multiple fields share the same name then the value of the last field is used.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <form name="login">
+    ///     <input name="username" type="text" value="mc1098" />
+    ///     <input name="remember" type="checkbox" value="yes" checked />
+    /// </form>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn read_login_form() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let values = rendered.assert_form_by_name("login").field_values();
+    ///
+    ///     assert_eq!(Some(&"mc1098".to_owned()), values.get("username"));
+    ///     assert_eq!(Some(&"yes".to_owned()), values.get("remember"));
+    /// }
+    /// ```
+    fn field_values(&self) -> HashMap<String, String>;
+}
+
+impl FormFields for HtmlFormElement {
+    fn field_values(&self) -> HashMap<String, String> {
+        let elements = self.elements();
+        let mut values = HashMap::new();
+        for i in 0..elements.length() {
+            let element = match elements.item(i) {
+                Some(element) => element,
+                None => continue,
+            };
+            let name = match element.get_attribute("name") {
+                Some(name) if !name.is_empty() => name,
+                _ => continue,
+            };
+
+            if element.has_attribute("disabled")
+                || element.tag_name().eq_ignore_ascii_case("button")
+            {
+                continue;
+            }
+
+            if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+                match input.type_().as_str() {
+                    "checkbox" | "radio" if !input.checked() => continue,
+                    "button" | "submit" | "reset" | "image" => continue,
+                    _ => {}
+                }
+            }
+
+            if let Some(value) = hyphae_utils::get_element_value(&element) {
+                values.insert(name, value);
+            }
+        }
+        values
+    }
+}
+
+/// An error indicating that no form was found with the given name.
+enum ByFormError {
+    /// No form could be found with the given name.
+    NotFound { name: String, inner_html: String },
+}
+
+impl Debug for ByFormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ByFormError::NotFound { name, inner_html } => {
+                write!(
+                    f,
+                    "\nNo form found with the name '{}' in the following HTML:{}",
+                    name,
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
+        }
+    }
+}
+
+impl Display for ByFormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ByFormError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae_utils::make_element_with_html_string;
+
+    #[wasm_bindgen_test]
+    fn read_form_values_into_map() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <form name="search">
+                <input name="query" type="search" value="other" />
+            </form>
+            <form id="shipping-form" name="shipping">
+                <input name="address" type="text" value="1 Rust Lane" />
+                <select name="speed">
+                    <option value="standard">Standard</option>
+                    <option value="express" selected>Express</option>
+                </select>
+                <textarea name="notes">Leave at door</textarea>
+                <input name="gift" type="checkbox" value="yes" />
+                <input name="coupon" type="text" value="SAVE10" disabled />
+                <input type="text" value="no name" />
+                <button name="submit">Submit</button>
+            </form>
+        "#,
+        )
+        .into();
+
+        let form = rendered.get_form_by_name("shipping").unwrap();
+        assert_eq!("shipping-form", form.id());
+
+        let values = form.field_values();
+        let expected: HashMap<String, String> = [
+            ("address", "1 Rust Lane"),
+            ("speed", "express"),
+            ("notes", "Leave at door"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        assert_eq!(expected, values);
+
+        assert!(rendered.get_form_by_name("billing").is_err());
+    }
+}
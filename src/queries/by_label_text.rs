@@ -16,6 +16,20 @@
 //! The `for` attribute of the label element must match the `id` attribute of the input or output element
 //! in order to be found.
 //!
+//! The label text is all of the text in the label, including the text of nested elements used for
+//! formatting. With the default [`MatchOptions`] runs of whitespace are collapsed to a single space
+//! and leading and trailing whitespace is ignored, which is the same text as the accessible name
+//! the label gives:
+//!
+//! ```html
+//! <label for="price">Price <strong>($)</strong></label>
+//!                    ^^^^^^^^^^^^^^^^^^^^^^^^^ the "label text" is "Price ($)"
+//! <input id="price" type="text" />
+//! ```
+//!
+//! Use [`get_by_label_text_with_options`](ByLabelText::get_by_label_text_with_options) with
+//! [`MatchOptions::trim`] or [`MatchOptions::normalizer`] to change how the label text is matched.
+//!
 //! # Generics
 //! Each trait function supports generics for convenience and to help narrow the scope of the search. If
 //! you are querying for a [`HtmlInputElement`](web_sys::HtmlInputElement) then you won't find a
@@ -35,7 +49,10 @@
 //! performing checked and unchecked casting between JS types.
 use std::fmt::Display;
 
-use hyphae::{Error, QueryElement};
+use hyphae::{
+    queries::{by_text::text_normalizer, MatchOptions},
+    Error, QueryElement,
+};
 
 use wasm_bindgen::JsCast;
 use web_sys::HtmlLabelElement;
@@ -194,6 +211,53 @@ pub trait ByLabelText {
         self.assert_by_label_text_inc(search).0
     }
 
+    /// Get a generic element by label text, returning [`None`] when no element is found.
+    ///
+    /// Unlike [`assert_by_label_text`](ByLabelText::assert_by_label_text) the rendered root
+    /// element is not removed when no element is found, so this can be used to check that an
+    /// element is absent and then continue the test.
+    fn query_by_label_text<T>(&self, search: &str) -> Option<T>
+    where
+        T: JsCast,
+    {
+        self.get_by_label_text(search).ok()
+    }
+
+    /// Get a generic element by label text using the [`MatchOptions`] given.
+    ///
+    /// [`get_by_label_text`](ByLabelText::get_by_label_text) uses the default [`MatchOptions`],
+    /// which collapses whitespace in the label text and the search term. Use
+    /// [`MatchOptions::trim`] as `false` to match the text content of the label exactly, or
+    /// [`MatchOptions::normalizer`] to normalize both texts in a custom way.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let options = MatchOptions {
+    ///     trim: false,
+    ///     ..Default::default()
+    /// };
+    /// let input: HtmlInputElement = rendered
+    ///     .get_by_label_text_with_options("Name: ", options)
+    ///     .unwrap();
+    /// ```
+    fn get_by_label_text_with_options<T>(
+        &self,
+        search: &str,
+        options: MatchOptions,
+    ) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_by_label_text_with_options`](ByLabelText::get_by_label_text_with_options).
+    fn assert_by_label_text_with_options<T>(&self, search: &str, options: MatchOptions) -> T
+    where
+        T: JsCast;
+
     /// Get a generic element and it's associated label, by the first label element which matches the
     /// label text and has the correct associated element type.
     ///
@@ -353,58 +417,93 @@ impl ByLabelText for QueryElement {
     where
         T: JsCast,
     {
-        let labels = match self.query_selector_all("label") {
-            Ok(labels) => labels,
-            Err(_) => {
-                return Err(Box::new(ByLabelTextError::LabelNotFound {
-                    search_term: search.to_owned(),
-                    inner_html: self.inner_html(),
-                }))
-            }
-        };
+        get_by_label_text_impl(self, search, &MatchOptions::default())
+    }
 
-        let mut labels_matching_search = 0;
-        let mut ids_found = vec![];
-
-        for i in 0..labels.length() {
-            let label = labels.get(i).unwrap();
-            if label
-                .text_content()
-                .map(|text| text == search)
-                .unwrap_or_default()
-            {
-                labels_matching_search += 1;
-                let label_element: HtmlLabelElement = label.unchecked_into();
-                if let Some(id) = label_element.get_attribute("for") {
-                    let node_list = self
-                        .query_selector_all(&format!("output[id={0}], input[id={0}]", id))
-                        .unwrap();
-
-                    for j in 0..node_list.length() {
-                        let node = node_list.get(j).unwrap();
-                        if let Ok(element) = node.dyn_into() {
-                            return Ok((element, label_element));
-                        }
-                    }
-                    // only push at the end - happy path == no allocation for vec
-                    ids_found.push(id);
-                }
-            }
+    fn assert_by_label_text_with_options<T>(&self, search: &str, options: MatchOptions) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_label_text_with_options(search, options);
+        if result.is_err() {
+            self.remove();
         }
+        result.unwrap()
+    }
 
-        if labels_matching_search == 0 {
-            Err(Box::new(ByLabelTextError::LabelNotFound {
-                search_term: search.to_owned(),
-                inner_html: self.inner_html(),
-            }))
-        } else {
-            Err(Box::new(ByLabelTextError::NoElementFound {
+    fn get_by_label_text_with_options<T>(
+        &self,
+        search: &str,
+        options: MatchOptions,
+    ) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        get_by_label_text_impl(self, search, &options).map(|(e, _)| e)
+    }
+}
+
+fn get_by_label_text_impl<T>(
+    rendered: &QueryElement,
+    search: &str,
+    options: &MatchOptions,
+) -> Result<(T, HtmlLabelElement), Error>
+where
+    T: JsCast,
+{
+    let normalize = text_normalizer(options);
+    let search_normalized = normalize(search);
+    let labels = match rendered.query_selector_all("label") {
+        Ok(labels) => labels,
+        Err(_) => {
+            return Err(Box::new(ByLabelTextError::LabelNotFound {
                 search_term: search.to_owned(),
-                no_of_labels: labels_matching_search,
-                ids_found,
-                inner_html: self.inner_html(),
+                inner_html: rendered.inner_html(),
             }))
         }
+    };
+
+    let mut labels_matching_search = 0;
+    let mut ids_found = vec![];
+
+    for i in 0..labels.length() {
+        let label = labels.get(i).unwrap();
+        if label
+            .text_content()
+            .map(|text| normalize(&text) == search_normalized)
+            .unwrap_or_default()
+        {
+            labels_matching_search += 1;
+            let label_element: HtmlLabelElement = label.unchecked_into();
+            if let Some(id) = label_element.get_attribute("for") {
+                let node_list = rendered
+                    .query_selector_all(&format!("output[id={0}], input[id={0}]", id))
+                    .unwrap();
+
+                for j in 0..node_list.length() {
+                    let node = node_list.get(j).unwrap();
+                    if let Ok(element) = node.dyn_into() {
+                        return Ok((element, label_element));
+                    }
+                }
+                // only push at the end - happy path == no allocation for vec
+                ids_found.push(id);
+            }
+        }
+    }
+
+    if labels_matching_search == 0 {
+        Err(Box::new(ByLabelTextError::LabelNotFound {
+            search_term: search.to_owned(),
+            inner_html: rendered.inner_html(),
+        }))
+    } else {
+        Err(Box::new(ByLabelTextError::NoElementFound {
+            search_term: search.to_owned(),
+            no_of_labels: labels_matching_search,
+            ids_found,
+            inner_html: rendered.inner_html(),
+        }))
     }
 }
 
@@ -509,7 +608,7 @@ pub mod tests {
     use wasm_bindgen_test::*;
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
-    use hyphae::QueryElement;
+    use hyphae::prelude::*;
     use hyphae_utils::make_element_with_html_string;
 
     use web_sys::{HtmlElement, HtmlInputElement};
@@ -616,6 +715,24 @@ pub mod tests {
         assert!(result.is_err());
     }
 
+    #[wasm_bindgen_test]
+    fn assert_and_query_by_label_text() {
+        let rendered = input_label_text();
+
+        let input: HtmlInputElement = rendered.assert_by_label_text("What needs to be done?");
+        assert_eq!("hi!", input.value());
+
+        let input: Option<HtmlInputElement> =
+            rendered.query_by_label_text("What needs to be done?");
+        assert_eq!(Some("hi!".to_owned()), input.map(|input| input.value()));
+
+        assert!(rendered
+            .query_by_label_text::<HtmlInputElement>("What doesn't need to be done?")
+            .is_none());
+        // the root is not removed when nothing is found
+        assert!(rendered.is_connected());
+    }
+
     #[wasm_bindgen_test]
     fn input_value_change() {
         let label_text = "What needs to be done?";
@@ -638,4 +755,50 @@ pub mod tests {
         assert_eq!(new_value, input_after.value());
         assert_eq!(input, input_after);
     }
+
+    #[wasm_bindgen_test]
+    fn label_text_with_nested_formatting() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <label for="price">Price <strong>($)</strong></label>
+            <input id="price" type="text" value="42" />
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.assert_by_label_text("Price ($)");
+        assert_eq!("price", input.id());
+
+        let by_role: HtmlInputElement =
+            rendered.assert_by_aria_role(AriaRole::TextBox, "Price ($)");
+        assert_eq!(input, by_role);
+    }
+
+    #[wasm_bindgen_test]
+    fn label_text_with_whitespace_runs() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <label for="price"></label>
+            <input id="price" type="text" />
+        "#,
+        )
+        .into();
+        // the formatting whitespace of an html string is removed so set the text directly
+        let label: HtmlElement = rendered.assert_first_by_selector("label");
+        label.set_text_content(Some("\n  Price\n  ($)\n"));
+
+        let input: HtmlInputElement = rendered.assert_by_label_text("Price ($)");
+        assert_eq!("price", input.id());
+
+        let exact = MatchOptions {
+            trim: false,
+            ..Default::default()
+        };
+        assert!(rendered
+            .get_by_label_text_with_options::<HtmlInputElement>("Price ($)", exact.clone())
+            .is_err());
+        let input: HtmlInputElement =
+            rendered.assert_by_label_text_with_options("\n  Price\n  ($)\n", exact);
+        assert_eq!("price", input.id());
+    }
 }
@@ -0,0 +1,269 @@
+//! Supports finding elements generically by their language or text direction.
+//!
+//! # Inheritance
+//!
+//! The `lang` and `dir` attributes are inherited, so an element without these attributes has the
+//! language and direction of the closest ancestor that does:
+//!
+//! ```html
+//! <section lang="ar" dir="rtl">
+//!     <p>مرحبا</p>
+//!     ^^^ lang="ar" and dir="rtl" from the section
+//! </section>
+//! ```
+//!
+//! The language matches the same way as the CSS `:lang()` pseudo-class, so a search for `"en"`
+//! will match the languages `"en"`, `"en-GB"` and `"en-US"`.
+use std::fmt::{Debug, Display};
+
+use hyphae::{Error, QueryElement, RawNodeListIter};
+
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// Enables querying elements generically by their language or text direction.
+///
+/// _See each trait function for examples._
+pub trait ByLang {
+    /// Get a generic element by its language, including the language inherited from ancestors.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <section lang="en">Hello</section>
+    /// <section id="arabic" lang="ar">مرحبا</section>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_arabic_section() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let section: HtmlElement = rendered.get_by_lang("ar").unwrap();
+    ///
+    ///     assert_eq!("arabic", section.id());
+    /// }
+    /// ```
+    fn get_by_lang<T>(&self, lang: &str) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of [`get_by_lang`](ByLang::get_by_lang).
+    fn assert_by_lang<T>(&self, lang: &str) -> T
+    where
+        T: JsCast;
+
+    /// Get a generic element by its text direction, including the direction inherited from
+    /// ancestors.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <div dir="rtl">
+    ///     <input id="name" type="text" />
+    /// </div>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_rtl_input() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let input: HtmlInputElement = rendered.get_by_dir("rtl").unwrap();
+    ///
+    ///     assert_eq!("name", input.id());
+    /// }
+    /// ```
+    fn get_by_dir<T>(&self, dir: &str) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of [`get_by_dir`](ByLang::get_by_dir).
+    fn assert_by_dir<T>(&self, dir: &str) -> T
+    where
+        T: JsCast;
+}
+
+/// The value of the attribute on the element or the closest ancestor that has the attribute.
+fn inherited_attribute(element: &Element, attribute: &str) -> Option<String> {
+    element
+        .closest(&format!("[{}]", attribute))
+        .ok()
+        .flatten()
+        .and_then(|element| element.get_attribute(attribute))
+}
+
+fn get_by_inherited_attribute<T, F>(
+    root: &QueryElement,
+    attribute: &'static str,
+    value: &str,
+    is_match: F,
+) -> Result<T, Error>
+where
+    T: JsCast,
+    F: Fn(&str) -> bool,
+{
+    let elements = root.query_selector_all("*").ok();
+    RawNodeListIter::<T>::new(elements)
+        .find(|element| {
+            inherited_attribute(element.unchecked_ref(), attribute)
+                .map(|value| is_match(&value))
+                .unwrap_or_default()
+        })
+        .ok_or_else(|| {
+            Box::new(ByLangError::NotFound {
+                attribute,
+                value: value.to_owned(),
+                inner_html: root.inner_html(),
+            }) as Error
+        })
+}
+
+impl ByLang for QueryElement {
+    fn assert_by_lang<T>(&self, lang: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_lang(lang);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_lang<T>(&self, lang: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let lang = lang.to_lowercase();
+        let subtag = format!("{}-", lang);
+        get_by_inherited_attribute(self, "lang", &lang, |value| {
+            let value = value.to_lowercase();
+            value == lang || value.starts_with(&subtag)
+        })
+    }
+
+    fn assert_by_dir<T>(&self, dir: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_dir(dir);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_dir<T>(&self, dir: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        get_by_inherited_attribute(self, "dir", dir, |value| value.eq_ignore_ascii_case(dir))
+    }
+}
+
+/// An error indicating that no element was found with the given language or direction.
+enum ByLangError {
+    /// No element could be found with the attribute value.
+    NotFound {
+        attribute: &'static str,
+        value: String,
+        inner_html: String,
+    },
+}
+
+impl Debug for ByLangError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ByLangError::NotFound {
+                attribute,
+                value,
+                inner_html,
+            } => {
+                write!(
+                    f,
+                    "\nNo element found with the {} '{}' in the following HTML:{}",
+                    attribute,
+                    value,
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
+        }
+    }
+}
+
+impl Display for ByLangError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ByLangError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae_utils::make_element_with_html_string;
+
+    use web_sys::{HtmlElement, HtmlInputElement, HtmlParagraphElement};
+
+    #[wasm_bindgen_test]
+    fn get_element_by_lang() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <section lang="en-GB">Hello</section>
+            <section id="arabic" lang="ar">
+                <p id="greeting">مرحبا</p>
+            </section>
+        "#,
+        )
+        .into();
+
+        let section: HtmlElement = rendered.get_by_lang("ar").unwrap();
+        assert_eq!("arabic", section.id());
+
+        // inherited from the section
+        let greeting: HtmlParagraphElement = rendered.get_by_lang("ar").unwrap();
+        assert_eq!("greeting", greeting.id());
+
+        // subtags match the primary language
+        let english: HtmlElement = rendered.get_by_lang("en").unwrap();
+        assert_eq!("Hello", english.inner_text());
+
+        assert!(rendered.get_by_lang::<HtmlElement>("fr").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_element_inside_rtl_container() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input id="ltr-name" type="text" />
+            <div dir="rtl">
+                <input id="rtl-name" type="text" />
+            </div>
+        "#,
+        )
+        .into();
+
+        let input: HtmlInputElement = rendered.get_by_dir("rtl").unwrap();
+        assert_eq!("rtl-name", input.id());
+    }
+}
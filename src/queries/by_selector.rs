@@ -38,7 +38,7 @@ use std::fmt::{Debug, Display};
 
 use wasm_bindgen::JsCast;
 
-use hyphae::{ElementIter, Error, QueryElement};
+use hyphae::{ElementIter, Error, QueryElement, RawNodeListIter};
 use web_sys::HtmlElement;
 
 /// Enables queries by selector.
@@ -218,6 +218,119 @@ pub trait BySelector {
     fn assert_all_by_selector<T>(&self, selector: &str) -> ElementIter<T>
     where
         T: JsCast;
+
+    /// Get the first generic element with the `data-*` attribute `key` equal to `value`.
+    ///
+    /// The `key` is the name of the attribute without the `data-` prefix. The `value` is quoted
+    /// in the selector so it can contain any characters, such as spaces or quotes.
+    ///
+    /// # Examples
+    /// Rendered html:
+    /// ```html
+    /// <div id="menu" data-state="open"></div>
+    /// ```
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_open_menu() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///     # QueryElement::new();
+    ///     let menu: HtmlElement = rendered.get_by_data("state", "open").unwrap();
+    ///
+    ///     assert_eq!("menu", menu.id());
+    /// }
+    /// ```
+    fn get_by_data<T>(&self, key: &str, value: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        self.get_first_by_selector(&data_selector(key, value))
+    }
+
+    /// A convenient method which unwraps the result of `get_by_data`.
+    fn assert_by_data<T>(&self, key: &str, value: &str) -> T
+    where
+        T: JsCast,
+    {
+        self.assert_first_by_selector(&data_selector(key, value))
+    }
+
+    /// Get all the generic elements with the `data-*` attribute `key` equal to `value`.
+    ///
+    /// See [`get_by_data`](BySelector::get_by_data) for how the `key` and `value` are used.
+    fn get_all_by_data<T>(&self, key: &str, value: &str) -> Result<ElementIter<T>, Error>
+    where
+        T: JsCast,
+    {
+        self.get_all_by_selector(&data_selector(key, value))
+    }
+
+    /// A convenient method which unwraps the result of `get_all_by_data`.
+    fn assert_all_by_data<T>(&self, key: &str, value: &str) -> ElementIter<T>
+    where
+        T: JsCast,
+    {
+        self.assert_all_by_selector(&data_selector(key, value))
+    }
+
+    /// Get the first generic element with the computed style `property` equal to `value`.
+    ///
+    /// Some state is only visible through CSS, such as a spinner with an `animation-name` or an
+    /// element hidden by a class with `display: none`. The `value` is compared with the computed
+    /// value, which is how the browser resolves it, so colours are `rgb(..)` and lengths are
+    /// in `px`.
+    ///
+    /// _Note: `getComputedStyle` is called for every element in the root element until a match
+    /// is found, which is much slower than the other queries on a large DOM. Prefer another query
+    /// when the state is also available as an attribute or class._
+    ///
+    /// # Examples
+    /// Rendered html:
+    /// ```html
+    /// <style>.spinning { animation-name: spin; }</style>
+    /// <div id="spinner" class="spinning"></div>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_spinning_element() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///     # QueryElement::new();
+    ///     let spinner: HtmlElement = rendered
+    ///         .get_by_computed_style("animation-name", "spin")
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("spinner", spinner.id());
+    /// }
+    /// ```
+    fn get_by_computed_style<T>(&self, property: &str, value: &str) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of `get_by_computed_style`.
+    fn assert_by_computed_style<T>(&self, property: &str, value: &str) -> T
+    where
+        T: JsCast;
+}
+
+/// The attribute selector for a `data-*` attribute with the value quoted.
+fn data_selector(key: &str, value: &str) -> String {
+    format!(
+        "[data-{}=\"{}\"]",
+        key,
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    )
 }
 
 impl BySelector for QueryElement {
@@ -268,6 +381,41 @@ impl BySelector for QueryElement {
         }
         result.unwrap()
     }
+
+    fn get_by_computed_style<T>(&self, property: &str, value: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let window = web_sys::window().expect("No global window object");
+        let node_list = self.query_selector_all("*").ok();
+        RawNodeListIter::<T>::new(node_list)
+            .find(|element| {
+                window
+                    .get_computed_style(element.unchecked_ref())
+                    .ok()
+                    .flatten()
+                    .and_then(|style| style.get_property_value(property).ok())
+                    .map_or(false, |computed| computed == value)
+            })
+            .ok_or_else(|| {
+                Box::new(BySelectorError::NoComputedStyleMatch {
+                    property: property.to_owned(),
+                    value: value.to_owned(),
+                    inner_html: self.inner_html(),
+                }) as Error
+            })
+    }
+
+    fn assert_by_computed_style<T>(&self, property: &str, value: &str) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_computed_style(property, value);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
 }
 
 enum BySelectorError {
@@ -277,6 +425,11 @@ enum BySelectorError {
         closest_element: HtmlElement,
     },
     NoElementFound(String),
+    NoComputedStyleMatch {
+        property: String,
+        value: String,
+        inner_html: String,
+    },
     SyntaxError(String),
 }
 
@@ -299,6 +452,17 @@ impl Debug for BySelectorError {
                     "\nNo element found that matches the given selector of '{selector}'."
                 )
             }
+            Self::NoComputedStyleMatch {
+                property,
+                value,
+                inner_html,
+            } => {
+                write!(
+                    f,
+                    "\nNo element found with the computed style '{property}: {value}' in the following HTML:{}",
+                    hyphae_utils::format_html(inner_html)
+                )
+            }
             Self::SyntaxError(selector) => {
                 write!(f, "\nSelector string of '{selector}' syntax is not valid!")
             }
@@ -451,4 +615,54 @@ mod tests {
             }
         }
     }
+
+    #[wasm_bindgen_test]
+    fn get_by_data_attribute() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="closed" data-state="closed"></div>
+            <div id="open" data-state="open"></div>
+            <div id="quoted" data-title='Say "hi" \ wave'></div>
+            <li data-state="open"></li>
+        "#,
+        )
+        .into();
+
+        let open: HtmlElement = rendered.assert_by_data("state", "open");
+        assert_eq!("open", open.id());
+
+        let quoted: HtmlElement = rendered.assert_by_data("title", r#"Say "hi" \ wave"#);
+        assert_eq!("quoted", quoted.id());
+
+        assert_eq!(
+            2,
+            rendered
+                .assert_all_by_data::<HtmlElement>("state", "open")
+                .count()
+        );
+        assert!(rendered
+            .get_all_by_data::<HtmlElement>("state", "hidden")
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn get_by_computed_display() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <span id="badge" style="display: block">3</span>
+            <div id="panel" style="display: none">Details</div>
+        "#,
+        )
+        .into();
+
+        let panel: HtmlElement = rendered.assert_by_computed_style("display", "none");
+        assert_eq!("panel", panel.id());
+
+        let badge: HtmlElement = rendered.assert_by_computed_style("display", "block");
+        assert_eq!("badge", badge.id());
+
+        assert!(rendered
+            .get_by_computed_style::<HtmlElement>("display", "grid")
+            .is_err());
+    }
 }
@@ -12,6 +12,15 @@
 //! 1 - "div text nodebutton text node"
 //! 2 - "button text node"
 //!
+//! # Exact matching
+//! The search term is matched against the whole inner text of an element, after trimming the
+//! leading and trailing whitespace of both, and never a part of it. Searching for "0" finds
+//! `<p>0</p>` or `<p> 0 </p>` but not `<p>10</p>` or `<p>Count: 0</p>`. When a parent element
+//! has the same inner text as its child, the child is returned.
+//!
+//! The content of `script`, `style` and `template` elements and comments are never shown to the
+//! user, so they are not matched.
+//!
 //! # Generics
 //! Each trait function supports generics for convenience and to help narrow the scope of the search. If
 //! you are querying for a [`HtmlButtonElement`](web_sys::HtmlInputElement) then you won't find a
@@ -34,10 +43,12 @@ use std::{
     ops::Deref,
 };
 
-use hyphae::{Error, QueryElement};
+use hyphae::{queries::MatchOptions, Error, QueryElement, RawNodeListIter};
 
 use wasm_bindgen::{prelude::Closure, JsCast};
-use web_sys::{HtmlElement, Node, NodeFilter, TreeWalker};
+use web_sys::{Element, HtmlElement, Node, NodeFilter, TreeWalker};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
 
 /// Enables queries by inner text.
 ///
@@ -45,6 +56,10 @@ use web_sys::{HtmlElement, Node, NodeFilter, TreeWalker};
 pub trait ByText {
     /// Get a generic element by the inner text.
     ///
+    /// The element found has a trimmed inner text that is exactly equal to the trimmed `search`,
+    /// an element that only contains the `search` as part of its text is not matched. See
+    /// [exact matching](self#exact-matching).
+    ///
     /// Using one of the generic types above as `T` will essentially skip the other two types of
     /// elements - if you want to find the very first element that matches the display value then use
     /// [`HtmlElement`](web_sys::HtmlElement).
@@ -146,9 +161,106 @@ pub trait ByText {
     fn assert_by_text<T>(&self, search: &str) -> T
     where
         T: JsCast;
+
+    /// Get a generic element by the inner text using the [`MatchOptions`] given.
+    ///
+    /// [`get_by_text`](ByText::get_by_text) uses the default [`MatchOptions`] which ignores the
+    /// leading and trailing whitespace of the inner text and search term and collapses each run
+    /// of whitespace to a single space, see [`default_normalizer`]. Use [`MatchOptions::trim`] to
+    /// match the whitespace exactly or [`MatchOptions::normalizer`] to normalize the text in your
+    /// own way.
+    ///
+    /// # Examples
+    ///
+    /// ## Get preformatted text with leading whitespace
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <pre id="code">  indented</pre>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_indented_code() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let options = MatchOptions {
+    ///         trim: false,
+    ///         ..Default::default()
+    ///     };
+    ///     let code: HtmlElement = rendered
+    ///         .get_by_text_with_options("  indented", options)
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("code", code.id());
+    /// }
+    /// ```
+    fn get_by_text_with_options<T>(&self, search: &str, options: MatchOptions) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_by_text_with_options`](ByText::get_by_text_with_options).
+    fn assert_by_text_with_options<T>(&self, search: &str, options: MatchOptions) -> T
+    where
+        T: JsCast;
+
+    /// Get a generic element by inner text that starts with `prefix` followed by the number `n`.
+    ///
+    /// The number can be written with or without grouping separators, so "1000", "1,000",
+    /// "1.000" and "1 000" all match `1000`. Any text after the number is ignored as long as it
+    /// doesn't start with a digit.
+    ///
+    /// When a parent element has the same inner text as its child, the child is returned.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <button id="clear">Clear completed (1,000)</button>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_clear_completed_count() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let clear: HtmlButtonElement = rendered
+    ///         .get_by_text_number("Clear completed (", 1000)
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("clear", clear.id());
+    /// }
+    /// ```
+    fn get_by_text_number<T>(&self, prefix: &str, n: i64) -> Result<T, Error>
+    where
+        T: JsCast;
+
+    /// A convenient method which unwraps the result of
+    /// [`get_by_text_number`](ByText::get_by_text_number).
+    fn assert_by_text_number<T>(&self, prefix: &str, n: i64) -> T
+    where
+        T: JsCast;
 }
 
-fn first_text_node_in_inner_text_match<T>(node: &Node, query: &str, exact: bool) -> Option<T>
+fn first_text_node_in_inner_text_match<T>(
+    node: &Node,
+    query: &str,
+    exact: bool,
+    normalize: fn(&str) -> String,
+) -> Option<T>
 where
     T: JsCast,
 {
@@ -157,22 +269,25 @@ where
     } else {
         hyphae_utils::is_close
     };
+    if is_in_non_text_element(node) {
+        return None;
+    }
+    let query = normalize(query);
+    let query = query.as_str();
     let mut node = node.clone();
-    while let Some(parent) = node
-        .parent_element()
-        .map(|e| e.unchecked_into::<HtmlElement>())
-    {
-        let inner_text = parent.inner_text();
+    while let Some(parent) = node.parent_element() {
+        let inner_text = normalize(&element_text(&parent));
+        let inner_text = inner_text.as_str();
         match inner_text.len().cmp(&query.len()) {
-            std::cmp::Ordering::Less if check(&query[..inner_text.len()], &inner_text) => {
+            std::cmp::Ordering::Less if check(&query[..inner_text.len()], inner_text) => {
                 node = parent.unchecked_into();
             }
-            std::cmp::Ordering::Equal if check(query, &inner_text) => {
-                return parent.dyn_into().ok();
+            std::cmp::Ordering::Equal if check(query, inner_text) => {
+                return svg_title_owner(parent).dyn_into().ok();
             }
             // we only want to check this when checking for close matches
             std::cmp::Ordering::Greater if !exact && check(&inner_text[..query.len()], query) => {
-                return parent.dyn_into().ok()
+                return svg_title_owner(parent).dyn_into().ok()
             }
             _ => break,
         }
@@ -180,7 +295,164 @@ where
     None
 }
 
+/// The normalizer used by the text queries when [`MatchOptions::normalizer`] is [`None`] and
+/// [`MatchOptions::trim`] is `true`.
+///
+/// Trims the leading and trailing whitespace and collapses each run of whitespace to a single
+/// space. Use this in a custom normalizer to keep the default behaviour.
+///
+/// # Examples
+/// ```
+/// use hyphae::queries::by_text::default_normalizer;
+///
+/// assert_eq!("Hello, World!", default_normalizer("  Hello,\n    World!  "));
+/// ```
+pub fn default_normalizer(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The normalizer to apply to the search term and the text of an element.
+pub(crate) fn text_normalizer(options: &MatchOptions) -> fn(&str) -> String {
+    match options.normalizer {
+        Some(normalizer) => normalizer,
+        None if options.trim => default_normalizer,
+        None => str::to_owned,
+    }
+}
+
+/// Elements with content that is never shown to the user as text.
+const NON_TEXT_ELEMENTS: &str = "script, style, template";
+
+/// Whether the node is, or is inside, an element with content that isn't text the user sees.
+///
+/// The inner text of an element that isn't rendered is the same as its text content, so without
+/// this check the CSS of a `style` element could be matched as text.
+fn is_in_non_text_element(node: &Node) -> bool {
+    let element = match node.dyn_ref::<Element>() {
+        Some(element) => Some(element.clone()),
+        None => node.parent_element(),
+    };
+    element
+        .and_then(|element| element.closest(NON_TEXT_ELEMENTS).ok().flatten())
+        .is_some()
+}
+
+/// The text of the element that the user sees.
+///
+/// SVG elements, such as the `text` of a chart, have no inner text so the text content is used
+/// instead.
+fn element_text(element: &Element) -> String {
+    match element.dyn_ref::<HtmlElement>() {
+        Some(element) => element.inner_text(),
+        None => element.text_content().unwrap_or_default(),
+    }
+}
+
+/// An SVG `title` element names its parent element, so the parent is the element found.
+fn svg_title_owner(element: Element) -> Element {
+    if element.namespace_uri().as_deref() == Some(SVG_NAMESPACE) && element.tag_name() == "title" {
+        element.parent_element().unwrap_or(element)
+    } else {
+        element
+    }
+}
+
+/// The characters used to group the digits of a number by different locales.
+const GROUP_SEPARATORS: &[char] = &[',', '.', ' ', '\'', '\u{a0}', '\u{202f}'];
+
+/// Parses the integer at the start of `text`, skipping the grouping separators between digits.
+fn leading_number(text: &str) -> Option<i64> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let mut digits = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+        } else if digits.is_empty()
+            || !GROUP_SEPARATORS.contains(&c)
+            || !chars.peek().map_or(false, char::is_ascii_digit)
+        {
+            break;
+        }
+    }
+    let n: i64 = digits.parse().ok()?;
+    Some(if negative { -n } else { n })
+}
+
+/// Finds the first `input` or `textarea` where the text the user sees, the value or the
+/// placeholder when there is no value, is equal to the query.
+fn first_input_value_match<T>(
+    root: &HtmlElement,
+    query: &str,
+    normalize: fn(&str) -> String,
+) -> Option<T>
+where
+    T: JsCast,
+{
+    let query = normalize(query);
+    let node_list = root.query_selector_all("input, textarea").ok();
+    RawNodeListIter::<T>::new(node_list).find(|element| {
+        let value = hyphae_utils::get_element_value(element)
+            .filter(|value| !value.is_empty())
+            .or_else(|| {
+                element
+                    .unchecked_ref::<HtmlElement>()
+                    .get_attribute("placeholder")
+            })
+            .unwrap_or_default();
+        normalize(&value) == query
+    })
+}
+
 impl ByText for QueryElement {
+    fn assert_by_text_number<T>(&self, prefix: &str, n: i64) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_text_number(prefix, n);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_text_number<T>(&self, prefix: &str, n: i64) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let elements = self.query_selector_all("*").ok();
+        let mut found: Option<T> = None;
+        // elements are in document order so the descendants of a match come straight after it
+        for element in RawNodeListIter::<T>::new(elements) {
+            if is_in_non_text_element(element.unchecked_ref()) {
+                continue;
+            }
+            let text = element_text(element.unchecked_ref());
+            if text.trim().strip_prefix(prefix).and_then(leading_number) != Some(n) {
+                continue;
+            }
+            let is_descendant = found.as_ref().map_or(true, |outer| {
+                outer
+                    .unchecked_ref::<Node>()
+                    .contains(Some(element.unchecked_ref()))
+            });
+            if !is_descendant {
+                break;
+            }
+            found = Some(element);
+        }
+
+        found.ok_or_else(|| {
+            Box::new(ByTextError::NotFound {
+                search_term: format!("{}{}", prefix, n),
+                inner_html: self.inner_html(),
+            }) as Error
+        })
+    }
+
     #[inline]
     fn assert_by_text<T>(&self, search: &str) -> T
     where
@@ -197,18 +469,43 @@ impl ByText for QueryElement {
     where
         T: JsCast,
     {
+        self.get_by_text_with_options(search, MatchOptions::default())
+    }
+
+    fn assert_by_text_with_options<T>(&self, search: &str, options: MatchOptions) -> T
+    where
+        T: JsCast,
+    {
+        let result = self.get_by_text_with_options(search, options);
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_by_text_with_options<T>(&self, search: &str, options: MatchOptions) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let normalize = text_normalizer(&options);
         let create_filter = |search: &str, exact| {
             let search = search.to_owned();
-            move |node| first_text_node_in_inner_text_match::<T>(&node, &search, exact).is_some()
+            move |node| {
+                first_text_node_in_inner_text_match::<T>(&node, &search, exact, normalize).is_some()
+            }
         };
 
         let walker =
             create_filtered_tree_walker(self, WhatToShow::ShowText, create_filter(search, true));
 
-        if let Some(result) = walker
-            .next_node()
-            .unwrap()
-            .and_then(|node| first_text_node_in_inner_text_match::<T>(&node, search, true))
+        if let Some(result) = walker.next_node().unwrap().and_then(|node| {
+            first_text_node_in_inner_text_match::<T>(&node, search, true, normalize)
+        }) {
+            Ok(result)
+        } else if let Some(result) = options
+            .include_input_values
+            .then(|| first_input_value_match::<T>(self, search, normalize))
+            .flatten()
         {
             Ok(result)
         } else {
@@ -221,10 +518,12 @@ impl ByText for QueryElement {
 
             let iter =
                 std::iter::from_fn(move || walker.next_node().ok().flatten()).filter_map(|node| {
-                    first_text_node_in_inner_text_match::<T>(&node, search, false).map(|e| {
-                        let element = e.unchecked_into::<HtmlElement>();
-                        (element.inner_text(), element)
-                    })
+                    first_text_node_in_inner_text_match::<T>(&node, search, false, normalize).map(
+                        |e| {
+                            let element = e.unchecked_into::<Element>();
+                            (element_text(&element), element)
+                        },
+                    )
                 });
 
             if let Some(closest) = hyphae_utils::closest(search, iter, |(key, _)| key) {
@@ -259,7 +558,7 @@ enum ByTextError {
     Closest {
         search_term: String,
         inner_html: String,
-        closest_element: HtmlElement,
+        closest_element: Element,
     },
 }
 
@@ -370,7 +669,7 @@ mod tests {
 
     use hyphae_utils::make_element_with_html_string;
 
-    use web_sys::{Element, HtmlButtonElement, HtmlLabelElement};
+    use web_sys::{HtmlButtonElement, HtmlInputElement, HtmlLabelElement};
 
     #[wasm_bindgen_test]
     fn traverse_the_element_tree_to_find_text() {
@@ -457,6 +756,62 @@ mod tests {
         assert!(not_found.is_ok());
     }
 
+    #[wasm_bindgen_test]
+    fn match_whitespace_exactly_without_trim() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <pre id="code">  indented</pre>
+        "#,
+        )
+        .into();
+
+        let options = MatchOptions {
+            trim: false,
+            ..Default::default()
+        };
+
+        let code: Element = rendered
+            .get_by_text_with_options("  indented", options.clone())
+            .unwrap();
+        assert_eq!("code", code.id());
+
+        assert!(rendered
+            .get_by_text_with_options::<Element>("indented", options)
+            .is_err());
+
+        // default options trim the whitespace
+        let code: Element = rendered.get_by_text("indented").unwrap();
+        assert_eq!("code", code.id());
+    }
+
+    #[wasm_bindgen_test]
+    fn find_input_by_displayed_value_with_option() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <input id="username" type="text" value="mc1098" />
+            <input id="email" type="email" placeholder="Email address" />
+        "#,
+        )
+        .into();
+
+        assert!(rendered.get_by_text::<Element>("mc1098").is_err());
+
+        let options = MatchOptions {
+            include_input_values: true,
+            ..Default::default()
+        };
+
+        let username: HtmlInputElement = rendered
+            .get_by_text_with_options("mc1098", options.clone())
+            .unwrap();
+        assert_eq!("username", username.id());
+
+        let email: HtmlInputElement = rendered
+            .get_by_text_with_options("Email address", options)
+            .unwrap();
+        assert_eq!("email", email.id());
+    }
+
     #[wasm_bindgen_test]
     fn find_close_match() {
         let rendered: QueryElement =
@@ -500,4 +855,137 @@ mod tests {
             }
         }
     }
+
+    #[wasm_bindgen_test]
+    fn find_svg_chart_label_and_title() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <svg id="chart" viewBox="0 0 100 100">
+                <title>Monthly revenue</title>
+                <g id="bar">
+                    <title>March: 40</title>
+                    <rect width="10" height="40" />
+                </g>
+                <text id="label" x="0" y="90">March</text>
+            </svg>
+        "#,
+        )
+        .into();
+
+        let label: Element = rendered.assert_by_text("March");
+        assert_eq!("label", label.id());
+
+        let bar: Element = rendered.assert_by_text("March: 40");
+        assert_eq!("bar", bar.id());
+
+        let chart: Element = rendered.assert_by_text("Monthly revenue");
+        assert_eq!("chart", chart.id());
+        assert_eq!(
+            "Monthly revenue",
+            hyphae_aria::element_accessible_name(&chart).unwrap()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn get_by_text_number_with_and_without_grouping() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <footer>
+                <button id="grouped">Clear completed (1,000)</button>
+                <span id="plain">Remaining (1000)</span>
+                <span>Archived (10000)</span>
+            </footer>
+        "#,
+        )
+        .into();
+
+        let grouped: HtmlButtonElement = rendered.assert_by_text_number("Clear completed (", 1000);
+        assert_eq!("grouped", grouped.id());
+
+        let plain: Element = rendered.assert_by_text_number("Remaining (", 1000);
+        assert_eq!("plain", plain.id());
+
+        assert!(rendered
+            .get_by_text_number::<Element>("Archived (", 1000)
+            .is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn by_text_skips_style_and_script_content() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <style>.banner::after { content: "Welcome back"; }</style>
+            <script type="text/template">Welcome back</script>
+            <!-- Welcome back -->
+            <p class="banner">Welcome back</p>
+        "#,
+        )
+        .into();
+
+        let banner: HtmlElement = rendered.assert_by_text("Welcome back");
+        assert_eq!("P", banner.tag_name());
+
+        rendered.set_inner_html("<style>Welcome back</style>");
+        assert!(rendered.get_by_text::<Element>("Welcome back").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn by_text_matches_whole_text_exactly() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div>
+                <span>Count: 0</span>
+                <p id="total">10</p>
+                <p id="count"> 0 </p>
+            </div>
+        "#,
+        )
+        .into();
+
+        // the number on its own, like the counter example
+        let count: HtmlElement = rendered.assert_by_text("0");
+        assert_eq!("count", count.id());
+
+        let total: HtmlElement = rendered.assert_by_text("10");
+        assert_eq!("total", total.id());
+
+        count.remove();
+        // "10" and "Count: 0" only contain the search term
+        assert!(rendered.get_by_text::<HtmlElement>("0").is_err());
+        assert!(rendered.get_by_text::<HtmlElement>("1").is_err());
+        assert!(rendered.get_by_text::<HtmlElement>("Count").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn custom_normalizer_removes_required_asterisk() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <label id="email-label">Email <span aria-hidden="true">*</span></label>
+        "#,
+        )
+        .into();
+
+        assert!(rendered.get_by_text::<HtmlElement>("Email").is_err());
+
+        fn without_asterisk(text: &str) -> String {
+            default_normalizer(text)
+                .trim_end_matches('*')
+                .trim_end()
+                .to_owned()
+        }
+        let options = MatchOptions {
+            normalizer: Some(without_asterisk),
+            ..Default::default()
+        };
+        let label: HtmlElement = rendered
+            .get_by_text_with_options("Email", options.clone())
+            .unwrap();
+        assert_eq!("email-label", label.id());
+
+        rendered.set_inner_html(r#"<label id="name-label">Required*</label>"#);
+        let label: HtmlElement = rendered
+            .get_by_text_with_options("Required", options)
+            .unwrap();
+        assert_eq!("name-label", label.id());
+    }
 }
@@ -0,0 +1,270 @@
+//! Supports chaining from the result of a query to a related element.
+//!
+//! Queries find an element by what the user can see, however, sometimes the element that you want
+//! to assert on is related to it - such as the list item that contains the text found.
+//!
+//! ```no_run
+//! # fn main() {}
+//! use wasm_bindgen_test::*;
+//! wasm_bindgen_test_configure!(run_in_browser);
+//! use hyphae::prelude::*;
+//! use web_sys::HtmlElement;
+//!
+//! #[wasm_bindgen_test]
+//! fn get_todo_item_by_text() {
+//!     let rendered: QueryElement = // feature dependent rendering
+//!         # QueryElement::new();
+//!     let item: HtmlElement = rendered
+//!         .get_by_text::<HtmlElement>("Gardening")
+//!         .closest_ancestor("li")
+//!         .unwrap();
+//! }
+//! ```
+use std::fmt::{Debug, Display};
+
+use hyphae::{Error, RawNodeListIter};
+
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+/// Enables chaining from a query result to a related element.
+///
+/// Any error from the original query is returned as is, so the error message will still be about
+/// the original query.
+///
+/// _See each trait function for examples._
+pub trait QueryResultExt {
+    /// Get the closest ancestor of the found element which matches the selector string and the
+    /// generic type `U`.
+    ///
+    /// The found element itself is not considered, even if it matches the selector.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <ul>
+    ///     <li id="gardening-item">
+    ///         <label>Gardening</label>
+    ///     </li>
+    /// </ul>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::{HtmlElement, HtmlLabelElement};
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_list_item_by_label_text() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let item: HtmlElement = rendered
+    ///         .get_by_text::<HtmlLabelElement>("Gardening")
+    ///         .closest_ancestor("li")
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("gardening-item", item.id());
+    /// }
+    /// ```
+    fn closest_ancestor<U>(self, selector: &str) -> Result<U, Error>
+    where
+        U: JsCast;
+
+    /// Get the first descendant of the found element which matches the selector string and the
+    /// generic type `U`.
+    ///
+    /// # Examples
+    ///
+    /// Rendered html:
+    /// ```html
+    /// <ul>
+    ///     <li>
+    ///         <label>Gardening</label>
+    ///         <button id="delete-gardening">X</button>
+    ///     </li>
+    /// </ul>
+    /// ```
+    /// Code:
+    /// ```no_run
+    /// # fn main() {}
+    /// use wasm_bindgen_test::*;
+    /// wasm_bindgen_test_configure!(run_in_browser);
+    /// use hyphae::prelude::*;
+    /// use web_sys::{HtmlButtonElement, HtmlElement};
+    ///
+    /// #[wasm_bindgen_test]
+    /// fn get_delete_button_of_item() {
+    ///     let rendered: QueryElement = // feature dependent rendering
+    ///         # QueryElement::new();
+    ///     let button: HtmlButtonElement = rendered
+    ///         .get_by_aria_role::<HtmlElement>(AriaRole::ListItem, "Gardening X")
+    ///         .child_by_selector("button")
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("delete-gardening", button.id());
+    /// }
+    /// ```
+    fn child_by_selector<U>(self, selector: &str) -> Result<U, Error>
+    where
+        U: JsCast;
+}
+
+impl<T> QueryResultExt for Result<T, Error>
+where
+    T: JsCast,
+{
+    fn closest_ancestor<U>(self, selector: &str) -> Result<U, Error>
+    where
+        U: JsCast,
+    {
+        let element = self?.unchecked_into::<Element>();
+        let mut parent = element.parent_element();
+        while let Some(ancestor) = parent {
+            let closest = ancestor
+                .closest(selector)
+                .map_err(|_| ChainError::SyntaxError(selector.to_owned()))?;
+            match closest {
+                Some(closest) => match closest.dyn_into() {
+                    Ok(closest) => return Ok(closest),
+                    // matches the selector but not the type so keep going up
+                    Err(closest) => parent = closest.parent_element(),
+                },
+                None => break,
+            }
+        }
+        Err(Box::new(ChainError::NoAncestorFound {
+            selector: selector.to_owned(),
+            outer_html: element.outer_html(),
+        }))
+    }
+
+    fn child_by_selector<U>(self, selector: &str) -> Result<U, Error>
+    where
+        U: JsCast,
+    {
+        let element = self?.unchecked_into::<Element>();
+        let children = element
+            .query_selector_all(selector)
+            .map_err(|_| ChainError::SyntaxError(selector.to_owned()))?;
+        RawNodeListIter::<U>::new(Some(children))
+            .next()
+            .ok_or_else(|| {
+                Box::new(ChainError::NoChildFound {
+                    selector: selector.to_owned(),
+                    outer_html: element.outer_html(),
+                }) as Error
+            })
+    }
+}
+
+/// An error indicating that no related element could be found from the result of a query.
+enum ChainError {
+    /// No ancestor of the element matched the selector and generic type.
+    NoAncestorFound {
+        selector: String,
+        outer_html: String,
+    },
+    /// No descendant of the element matched the selector and generic type.
+    NoChildFound {
+        selector: String,
+        outer_html: String,
+    },
+    SyntaxError(String),
+}
+
+impl Debug for ChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainError::NoAncestorFound {
+                selector,
+                outer_html,
+            } => {
+                write!(
+                    f,
+                    "\nNo ancestor found that matches the given selector of '{}' for the element:{}",
+                    selector,
+                    hyphae_utils::format_html(outer_html)
+                )
+            }
+            ChainError::NoChildFound {
+                selector,
+                outer_html,
+            } => {
+                write!(
+                    f,
+                    "\nNo child found that matches the given selector of '{}' in the element:{}",
+                    selector,
+                    hyphae_utils::format_html(outer_html)
+                )
+            }
+            ChainError::SyntaxError(selector) => {
+                write!(
+                    f,
+                    "\nSelector string of '{}' syntax is not valid!",
+                    selector
+                )
+            }
+        }
+    }
+}
+
+impl Display for ChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae::prelude::*;
+    use hyphae_utils::make_element_with_html_string;
+
+    use web_sys::{HtmlButtonElement, HtmlElement, HtmlLabelElement};
+
+    #[wasm_bindgen_test]
+    fn ascend_from_label_text_to_list_item() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <ul>
+                <li id="gardening-item">
+                    <label>Gardening</label>
+                    <button id="delete-gardening">X</button>
+                </li>
+                <li id="cooking-item">
+                    <label>Cooking</label>
+                </li>
+            </ul>
+        "#,
+        )
+        .into();
+
+        let item: HtmlElement = rendered
+            .get_by_text::<HtmlLabelElement>("Gardening")
+            .closest_ancestor("li")
+            .unwrap();
+        assert_eq!("gardening-item", item.id());
+
+        let button: HtmlButtonElement = rendered
+            .get_by_text::<HtmlLabelElement>("Gardening")
+            .closest_ancestor::<HtmlElement>("li")
+            .child_by_selector("button")
+            .unwrap();
+        assert_eq!("delete-gardening", button.id());
+
+        assert!(rendered
+            .get_by_text::<HtmlLabelElement>("Cooking")
+            .closest_ancestor::<HtmlElement>("li")
+            .child_by_selector::<HtmlButtonElement>("button")
+            .is_err());
+    }
+}
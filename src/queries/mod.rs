@@ -3,35 +3,1233 @@
 //! This module helps to query the DOM of a rendered root element. The goal is to use high/medium level
 //! APIs so that the DOM can be queried in a manner similar to how a user might navigate the UI.
 
-use std::ops::Deref;
+use std::{
+    cell::{Cell, RefCell},
+    fmt::{Debug, Display},
+    ops::Deref,
+    rc::Rc,
+    time::Duration,
+};
 
-use wasm_bindgen::JsCast;
-use web_sys::HtmlElement;
+use hyphae::{ElementIter, Error, RawNodeListIter};
+use hyphae_aria::{
+    property::{AriaProperty, HasPopupToken},
+    role::AriaRole,
+    ToQueryString,
+};
+
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{
+    DomRect, Element, Event, EventInit, HtmlButtonElement, HtmlElement, HtmlFormElement,
+    HtmlIFrameElement, HtmlInputElement, MouseEvent, MouseEventInit,
+};
 
 pub mod by_aria;
 pub mod by_display_value;
+pub mod by_form;
 pub mod by_label_text;
+pub mod by_lang;
 pub mod by_placeholder_text;
 pub mod by_selector;
 pub mod by_text;
+pub mod chain;
+mod trace;
+
+pub use trace::TracedEvent;
+
+use by_aria::ByAria;
+use by_text::ByText;
+
+/// Options to fine tune which elements are matched by a query.
+///
+/// The [`Default`] options are the ones used by the queries that don't accept options.
+///
+/// # Examples
+/// ```no_run
+/// use hyphae::prelude::*;
+///
+/// let options = MatchOptions {
+///     include_hidden: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct MatchOptions {
+    /// Include elements that are hidden from the user, such as the content of a closed `details`
+    /// element or an element with an ancestor that has `aria-hidden="true"` or `display: none`.
+    ///
+    /// Default: `false`
+    pub include_hidden: bool,
+    /// Ignore leading and trailing whitespace, and collapse each run of whitespace to a single
+    /// space, when matching text.
+    ///
+    /// Use `false` to match whitespace exactly, such as the content of a `pre` element.
+    ///
+    /// _Note: Accessible names are always trimmed._
+    ///
+    /// Default: `true`
+    pub trim: bool,
+    /// Normalize the search term and the text of each element before comparing them, such as
+    /// removing punctuation. This replaces the normalization of [`trim`](MatchOptions::trim).
+    ///
+    /// Use [`default_normalizer`](by_text::default_normalizer) within the function to keep the
+    /// default normalization. This is only used by the text and label text queries, such as
+    /// [`get_by_text_with_options`](by_text::ByText::get_by_text_with_options).
+    ///
+    /// Default: [`None`]
+    pub normalizer: Option<fn(&str) -> String>,
+    /// Match the text that the user sees in `input` and `textarea` elements, which is the value
+    /// or the placeholder when there is no value.
+    ///
+    /// Text queries only match text nodes, so without this option an input can only be found by
+    /// [`get_by_display_value`](by_display_value::ByDisplayValue::get_by_display_value) or
+    /// [`get_by_placeholder_text`](by_placeholder_text::ByPlaceholderText::get_by_placeholder_text).
+    ///
+    /// Default: `false`
+    pub include_input_values: bool,
+    /// Only match elements that can be reached with the keyboard using the `Tab` key.
+    ///
+    /// Elements with `tabindex="-1"`, disabled elements and elements that are not focusable at
+    /// all, such as a `div` without a `tabindex`, are skipped. This is only used by the ARIA role
+    /// queries, such as
+    /// [`get_by_aria_role_with_options`](by_aria::ByAria::get_by_aria_role_with_options).
+    ///
+    /// Default: `false`
+    pub focusable_only: bool,
+    /// Only match elements that are enabled.
+    ///
+    /// An element is disabled when it has `aria-disabled="true"`, is a disabled form control or
+    /// is inside a composite widget, such as a `toolbar` or `menu`, that has
+    /// `aria-disabled="true"`. This is only used by the ARIA role queries, such as
+    /// [`get_by_aria_role_with_options`](by_aria::ByAria::get_by_aria_role_with_options).
+    ///
+    /// Default: `false`
+    pub enabled_only: bool,
+    /// Also match the `aria-roledescription` of an element as if it was the accessible name.
+    ///
+    /// Some design systems describe an icon button with `aria-roledescription` rather than an
+    /// accessible name, so the role description is the only text that a user hears. This is
+    /// only used by the ARIA role queries, such as
+    /// [`get_by_aria_role_with_options`](by_aria::ByAria::get_by_aria_role_with_options).
+    ///
+    /// Default: `false`
+    pub include_roledescription: bool,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        Self {
+            include_hidden: false,
+            trim: true,
+            normalizer: None,
+            include_input_values: false,
+            focusable_only: false,
+            enabled_only: false,
+            include_roledescription: false,
+        }
+    }
+}
+
+/// The roles of the elements that a user can interact with, used by
+/// [`QueryElement::interactive_elements`].
+const INTERACTIVE_ROLES: &[AriaRole] = &[
+    AriaRole::Button,
+    AriaRole::Checkbox,
+    AriaRole::Combobox,
+    AriaRole::Link,
+    AriaRole::ListBox,
+    AriaRole::MenuItem,
+    AriaRole::MenuItemCheckbox,
+    AriaRole::MenuItemRadio,
+    AriaRole::Option,
+    AriaRole::Radio,
+    AriaRole::Searchbox,
+    AriaRole::Slider,
+    AriaRole::SpinButton,
+    AriaRole::Switch,
+    AriaRole::Tab,
+    AriaRole::TextBox,
+    AriaRole::TreeItem,
+];
+
+/// The ARIA attributes that reference other elements by id, checked by
+/// [`QueryElement::assert_aria_refs_valid`].
+const ARIA_ID_REF_ATTRIBUTES: &[&str] = &[
+    "aria-activedescendant",
+    "aria-controls",
+    "aria-describedby",
+    "aria-details",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-labelledby",
+    "aria-owns",
+];
+
+/// Wrapper around a root element which has been rendered.
+pub struct QueryElement {
+    root: HtmlElement,
+    // a scoped element is part of another root so is not removed when dropped
+    scoped: bool,
+    trace: RefCell<Option<trace::EventTrace>>,
+    cleanups: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+impl QueryElement {
+    /// Wrap rendered root element ready to be queried.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// let rendered = QueryElement::new();
+    /// // .. use `rendered` to get elements and perform tests
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap a new root element with a unique id, isolated from other tests.
+    ///
+    /// The root element of [`QueryElement::new`] always has the id `hyphae-test-app`, whereas
+    /// each isolated root element has a unique id, such as `hyphae-test-app-3`.
+    ///
+    /// With the `storage` feature [`storage_key`](QueryElement::storage_key) can be used to
+    /// namespace the `localStorage` keys used by each test, and any item with a key namespaced by
+    /// this root is removed when it is dropped. Other items, including those of other isolated
+    /// roots, are left alone.
+    ///
+    /// _Note: `wasm-bindgen-test` runs tests one at a time, however, the futures of `async` tests
+    /// can still overlap, for example, when a test doesn't await a task that it spawned._
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let first = QueryElement::isolated();
+    /// let second = QueryElement::isolated();
+    /// assert_ne!(first.id(), second.id());
+    /// ```
+    pub fn isolated() -> Self {
+        thread_local! {
+            static NEXT_ID: Cell<usize> = Cell::new(0);
+        }
+        let id = NEXT_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+        let rendered = Self::default();
+        rendered.set_id(&format!("hyphae-test-app-{}", id));
+
+        #[cfg(feature = "storage")]
+        {
+            let storage = local_storage();
+            let prefix = format!("{}:", rendered.id());
+            rendered.on_cleanup(move || {
+                for key in storage_keys(&storage) {
+                    if key.starts_with(&prefix) {
+                        storage.remove_item(&key).unwrap();
+                    }
+                }
+            });
+        }
+
+        rendered
+    }
+
+    /// Namespace a `localStorage` key with the id of the root element, so tests using
+    /// [`QueryElement::isolated`] don't share storage items.
+    ///
+    /// _Requires the `storage` feature._
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::isolated();
+    /// let key = rendered.storage_key("todos");
+    /// // .. configure the app under test to use `key`
+    /// ```
+    #[cfg(feature = "storage")]
+    pub fn storage_key(&self, key: &str) -> String {
+        format!("{}:{}", self.id(), key)
+    }
+
+    /// Render static HTML into a new root element ready to be queried.
+    ///
+    /// The formatting whitespace (new lines, tabs and indentation) is removed from `html` so
+    /// the indentation of a raw string doesn't add text nodes. The root element is attached to
+    /// the body and, like [`QueryElement::new`], is removed when dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// let rendered = QueryElement::render_html(
+    ///     r#"
+    ///     <button>Ok</button>
+    /// "#,
+    /// );
+    /// let ok: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Ok");
+    /// ```
+    pub fn render_html(html: &str) -> Self {
+        hyphae_utils::make_element_with_html_string(html).into()
+    }
+
+    /// Scope queries to an element within this root element.
+    ///
+    /// Queries using the returned [`QueryElement`] will only find elements in the subtree of
+    /// `element` and any errors will only show the HTML of this subtree.
+    ///
+    /// The scoped [`QueryElement`] does not remove `element` from the DOM when dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::{HtmlButtonElement, HtmlElement};
+    ///
+    /// let rendered = QueryElement::new();
+    /// let dialog: HtmlElement = rendered.assert_by_aria_role(AriaRole::Dialog, "Delete item");
+    /// // there might be many "Ok" buttons but only one in the dialog
+    /// let ok: HtmlButtonElement = rendered
+    ///     .within(&dialog)
+    ///     .assert_by_aria_role(AriaRole::Button, "Ok");
+    /// ```
+    pub fn within(&self, element: &HtmlElement) -> QueryElement {
+        Self {
+            root: element.clone(),
+            scoped: true,
+            trace: RefCell::default(),
+            cleanups: RefCell::default(),
+        }
+    }
+
+    /// Scope queries to the element found by a query, see [`within`](QueryElement::within).
+    ///
+    /// This allows finding an element and scoping queries to it in one expression, returning
+    /// the error of the query if the element wasn't found.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// # fn within_result_example() -> Result<(), Error> {
+    /// let rendered = QueryElement::new();
+    /// let ok: HtmlButtonElement = rendered
+    ///     .within_result(rendered.get_by_aria_role(AriaRole::Dialog, "Delete item"))?
+    ///     .get_by_aria_role(AriaRole::Button, "Ok")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn within_result(&self, result: Result<HtmlElement, Error>) -> Result<QueryElement, Error> {
+        result.map(|element| self.within(&element))
+    }
+
+    /// Simulates the user clicking away from the rendered elements.
+    ///
+    /// Fires bubbling `mousedown` and `click` [`MouseEvent`](web_sys::MouseEvent)s on
+    /// `document.body` and then blurs the active element. The target of the events is the body,
+    /// which is outside of any rendered widget, so outside click detection will be triggered.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let menu: HtmlElement = rendered.assert_by_aria_role(AriaRole::Menu, "Options");
+    /// rendered.click_outside();
+    /// assert!(!menu.is_connected());
+    /// ```
+    pub fn click_outside(&self) {
+        let document = web_sys::window()
+            .and_then(|w| w.document())
+            .expect("Cannot get global document");
+        let body = document.body().expect("Cannot get body element");
+
+        let mut event_init = MouseEventInit::new();
+        event_init.bubbles(true);
+        for &event_type in ["mousedown", "click"].iter() {
+            let event =
+                MouseEvent::new_with_mouse_event_init_dict(event_type, &event_init).unwrap();
+            body.dispatch_event(&event).unwrap();
+        }
+
+        if let Some(active) = document
+            .active_element()
+            .and_then(|e| e.dyn_into::<HtmlElement>().ok())
+        {
+            active.blur().unwrap();
+        }
+    }
+
+    /// Click a submit button and make sure that the form it belongs to is submitted.
+    ///
+    /// Clicking a submit button in a form fires the `submit` event of the form in the browser,
+    /// however, this is not always the case in a test, for example, when the click is handled
+    /// without the default action. When the `button` is a submit `button` or `input` in a form
+    /// and clicking it does not fire a `submit` event on the form, a cancelable `submit`
+    /// [`Event`] is dispatched to the form instead, so the form is only submitted once.
+    ///
+    /// Any other element is just clicked.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let save: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+    /// rendered.click_submit(&save);
+    /// ```
+    pub fn click_submit(&self, button: &HtmlElement) {
+        let form = match submit_button_form(button) {
+            Some(form) => form,
+            None => return button.click(),
+        };
+
+        let submitted = Rc::new(Cell::new(false));
+        let listener = {
+            let submitted = Rc::clone(&submitted);
+            Closure::wrap(Box::new(move || submitted.set(true)) as Box<dyn Fn()>)
+        };
+        form.add_event_listener_with_callback_and_bool(
+            "submit",
+            listener.as_ref().unchecked_ref(),
+            true,
+        )
+        .unwrap();
+
+        button.click();
+
+        form.remove_event_listener_with_callback_and_bool(
+            "submit",
+            listener.as_ref().unchecked_ref(),
+            true,
+        )
+        .unwrap();
+
+        if !submitted.get() {
+            let mut init = EventInit::new();
+            init.bubbles(true);
+            init.cancelable(true);
+            let event = Event::new_with_event_init_dict("submit", &init).unwrap();
+            form.dispatch_event(&event).unwrap();
+        }
+    }
+
+    /// Scope queries to the article with a first heading that has the accessible name given.
+    ///
+    /// An article is an `article` element or an element with `role="article"`. Only the first
+    /// heading of each article is checked, as this is the heading that names the article when a
+    /// screen reader user navigates by articles.
+    ///
+    /// The scoped [`QueryElement`] does not remove the article from the DOM when dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let article = rendered.within_article("Release notes").unwrap();
+    /// let share: HtmlButtonElement = article.assert_by_aria_role(AriaRole::Button, "Share");
+    /// ```
+    pub fn within_article(&self, heading_name: &str) -> Result<QueryElement, Error> {
+        let articles = self.query_selector_all("article, [role=article]").ok();
+        RawNodeListIter::<HtmlElement>::new(articles)
+            .find(|article| {
+                article
+                    .query_selector("h1, h2, h3, h4, h5, h6, [role=heading]")
+                    .ok()
+                    .flatten()
+                    .and_then(|heading| hyphae_aria::element_accessible_name(&heading).ok())
+                    .map(|name| name == heading_name)
+                    .unwrap_or_default()
+            })
+            .map(|article| self.within(&article))
+            .ok_or_else(|| {
+                Box::new(ScopeError::ArticleNotFound {
+                    heading_name: heading_name.to_owned(),
+                    inner_html: self.inner_html(),
+                }) as Error
+            })
+    }
+
+    /// Scope queries to the `main` landmark, ignoring the header, footer and navigation.
+    ///
+    /// The `main` landmark is a `main` element or an element with `role="main"`.
+    ///
+    /// The scoped [`QueryElement`] does not remove the landmark from the DOM when dropped.
+    ///
+    /// # Errors
+    /// If there is no `main` landmark or there is more than one.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let main = rendered.main().unwrap();
+    /// let heading: HtmlElement = main.assert_by_aria_role(AriaRole::Heading, "Dashboard");
+    /// ```
+    pub fn main(&self) -> Result<QueryElement, Error> {
+        let landmarks = self
+            .query_selector_all(&AriaRole::Main.to_query_string())
+            .ok();
+        let mut landmarks = RawNodeListIter::<HtmlElement>::new(landmarks);
+        match (landmarks.next(), landmarks.next()) {
+            (Some(main), None) => Ok(self.within(&main)),
+            (Some(_), Some(_)) => Err(Box::new(ScopeError::MultipleMain {
+                inner_html: self.inner_html(),
+            })),
+            (None, _) => Err(Box::new(ScopeError::MainNotFound {
+                inner_html: self.inner_html(),
+            })),
+        }
+    }
+
+    /// Scope queries to the table with the accessible name given.
+    ///
+    /// The accessible name of a table comes from its `caption` or an `aria-label`. This helps to
+    /// find a header or cell by name when there are many tables with the same headers.
+    ///
+    /// The scoped [`QueryElement`] does not remove the table from the DOM when dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let header: HtmlElement = rendered
+    ///     .within_table("Invoices")
+    ///     .unwrap()
+    ///     .assert_by_aria_role(AriaRole::ColumnHeader, "Amount");
+    /// ```
+    pub fn within_table(&self, name: &str) -> Result<QueryElement, Error> {
+        let table: HtmlElement = self.get_by_aria_role(AriaRole::Table, name)?;
+        Ok(self.within(&table))
+    }
+
+    /// Scope queries to the grid with the accessible name given.
+    ///
+    /// Only an element with `role="grid"` is a grid, so a `table` or an element with
+    /// `role="treegrid"` with the same name is not found. Use [`rows`](QueryElement::rows) on the
+    /// scoped [`QueryElement`] to enumerate the rows of the grid.
+    ///
+    /// The scoped [`QueryElement`] does not remove the grid from the DOM when dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let cell: HtmlElement = rendered
+    ///     .within_grid("Files")
+    ///     .unwrap()
+    ///     .assert_by_aria_role(AriaRole::GridCell, "report.pdf");
+    /// ```
+    pub fn within_grid(&self, name: &str) -> Result<QueryElement, Error> {
+        let grid: HtmlElement = self.get_by_aria_role(AriaRole::Grid, name)?;
+        Ok(self.within(&grid))
+    }
+
+    /// Scope queries to the group with the accessible name given, such as a `fieldset` named by
+    /// its `legend`.
+    ///
+    /// Forms often group related fields that share labels, for example a "Shipping" and a
+    /// "Billing" address each with a "Street" text box. Scoping to the group first makes those
+    /// labels unique.
+    ///
+    /// The scoped [`QueryElement`] does not remove the group from the DOM when dropped.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlInputElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let street: HtmlInputElement = rendered
+    ///     .within_group("Shipping")
+    ///     .unwrap()
+    ///     .assert_by_aria_role(AriaRole::TextBox, "Street");
+    /// ```
+    pub fn within_group(&self, legend_name: &str) -> Result<QueryElement, Error> {
+        let group: HtmlElement = self.get_by_aria_role(AriaRole::Group, legend_name)?;
+        Ok(self.within(&group))
+    }
+
+    /// Every element with the `row` role in the root element, in document order.
+    ///
+    /// This includes `tr` elements and elements with `role="row"`, such as the rows of a grid.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let grid = rendered.within_grid("Files").unwrap();
+    /// // a header row and two file rows
+    /// assert_eq!(3, grid.rows().count());
+    /// ```
+    pub fn rows(&self) -> ElementIter<HtmlElement> {
+        ElementIter::new(
+            self.query_selector_all(&AriaRole::Row.to_query_string())
+                .ok(),
+        )
+    }
+
+    /// Every interactive element with its ARIA role and accessible name, in document order.
+    ///
+    /// The interactive roles include buttons, links, form controls, menu items, options and tabs.
+    /// Hidden elements are skipped, as they can't be interacted with. This is useful for an
+    /// accessibility audit, such as checking that every interactive element has an accessible
+    /// name.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// for (role, name, element) in rendered.interactive_elements() {
+    ///     assert!(!name.is_empty(), "{:?} has no accessible name: {}", role, element.outer_html());
+    /// }
+    /// ```
+    pub fn interactive_elements(&self) -> Vec<(AriaRole, String, HtmlElement)> {
+        let selector = INTERACTIVE_ROLES
+            .iter()
+            .map(|role| role.to_query_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let node_list = self.query_selector_all(&selector).ok();
+        RawNodeListIter::<HtmlElement>::new(node_list)
+            .filter(|element| !by_aria::is_hidden(element))
+            .filter_map(|element| {
+                let role = *INTERACTIVE_ROLES
+                    .iter()
+                    .find(|role| element.matches(&role.to_query_string()).unwrap_or_default())?;
+                let name = hyphae_aria::element_accessible_name(&element).unwrap_or_default();
+                Some((role, name, element))
+            })
+            .collect()
+    }
+
+    /// Writes the formatted HTML of the root element to the debug sink, which is `console.log`
+    /// by default.
+    ///
+    /// Use [`set_debug_sink`](crate::debug::set_debug_sink) to write the HTML somewhere else.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// // what does the HTML look like at this point?
+    /// rendered.debug();
+    /// ```
+    pub fn debug(&self) {
+        crate::debug::write(&hyphae_utils::format_html(&self.inner_html()));
+    }
+
+    /// Writes each interactive element with its ARIA role and accessible name to the debug sink,
+    /// which is `console.log` by default.
+    ///
+    /// Each line is the role, the accessible name and the HTML of an element from
+    /// [`interactive_elements`](QueryElement::interactive_elements), which helps to find out why
+    /// a query by role isn't finding the element expected.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// // Button "Save": <button>Save</button>
+    /// rendered.debug_aria();
+    /// ```
+    pub fn debug_aria(&self) {
+        let output = self
+            .interactive_elements()
+            .into_iter()
+            .map(|(role, name, element)| format!("{:?} {:?}: {}", role, name, element.outer_html()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        crate::debug::write(&output);
+    }
+
+    /// Every element that triggers a popup, using the `aria-haspopup` attribute, in document order.
+    ///
+    /// With `None` this finds every trigger, skipping elements with `aria-haspopup="false"`. With
+    /// a [`HasPopupToken`] only triggers for that type of popup are found - `true` and `menu` are
+    /// equivalent, so [`HasPopupToken::True`] and [`HasPopupToken::Menu`] find the same elements.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// for trigger in rendered.get_all_popup_triggers(Some(HasPopupToken::Dialog)) {
+    ///     trigger.click();
+    ///     rendered.assert_by_aria_role::<web_sys::HtmlElement>(AriaRole::Dialog, "Confirm");
+    /// }
+    /// ```
+    pub fn get_all_popup_triggers(&self, popup: Option<HasPopupToken>) -> ElementIter<HtmlElement> {
+        let selector = match popup {
+            None => r#"[aria-haspopup]:not([aria-haspopup="false"])"#.to_owned(),
+            Some(HasPopupToken::True) | Some(HasPopupToken::Menu) => [
+                AriaProperty::HasPopup(HasPopupToken::True),
+                AriaProperty::HasPopup(HasPopupToken::Menu),
+            ]
+            .iter()
+            .map(ToQueryString::to_query_string)
+            .collect::<Vec<_>>()
+            .join(","),
+            Some(token) => AriaProperty::HasPopup(token).to_query_string(),
+        };
+        ElementIter::new(self.query_selector_all(&selector).ok())
+    }
+
+    /// Asserts that every ARIA attribute referencing an element by id, such as `aria-labelledby`
+    /// or `aria-controls`, references an element that exists.
+    ///
+    /// The root element and all of its descendants are checked. Referenced elements are looked
+    /// up in the whole document, so a reference to an element outside of the root is valid.
+    ///
+    /// # Panics
+    /// If any id referenced does not exist, listing every dangling reference.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// rendered.assert_aria_refs_valid();
+    /// ```
+    pub fn assert_aria_refs_valid(&self) {
+        let result = self.get_aria_refs_valid();
+        if result.is_err() {
+            self.remove();
+        }
+        result.unwrap()
+    }
+
+    fn get_aria_refs_valid(&self) -> Result<(), Error> {
+        let selector = ARIA_ID_REF_ATTRIBUTES
+            .iter()
+            .map(|attribute| format!("[{}]", attribute))
+            .collect::<Vec<_>>()
+            .join(",");
+        let node_list = self.query_selector_all(&selector).ok();
+        let root: &Element = &self.root;
+        let elements = std::iter::once(root.clone())
+            .filter(|root| root.matches(&selector).unwrap_or_default())
+            .chain(RawNodeListIter::<Element>::new(node_list));
+
+        let mut dangling = vec![];
+        for element in elements {
+            let document = element.owner_document();
+            for attribute in ARIA_ID_REF_ATTRIBUTES {
+                let ids = element.get_attribute(attribute).unwrap_or_default();
+                for id in ids.split_whitespace() {
+                    let exists = document
+                        .as_ref()
+                        .and_then(|document| document.get_element_by_id(id))
+                        .is_some();
+                    if !exists {
+                        let html = element.outer_html();
+                        let opening_tag = html.split_inclusive('>').next().unwrap_or_default();
+                        dangling.push(format!("{}=\"{}\" on {}", attribute, id, opening_tag));
+                    }
+                }
+            }
+        }
+
+        if dangling.is_empty() {
+            Ok(())
+        } else {
+            Err(Box::new(AriaRefError {
+                dangling,
+                inner_html: self.inner_html(),
+            }))
+        }
+    }
+
+    /// The element referenced by the `aria-activedescendant` of the control, if any.
+    ///
+    /// A combobox or listbox often keeps focus on the control and tracks the option the user has
+    /// moved to with `aria-activedescendant`, so this is the element a screen reader announces
+    /// rather than the element with focus. The referenced element is looked up in the whole
+    /// document.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let listbox: HtmlElement = rendered.assert_by_aria_role(AriaRole::ListBox, "Fruit");
+    /// dispatch_key_event(&listbox, KeyEventType::KeyDown, Key::ArrowDown);
+    /// let active = rendered.active_descendant(&listbox).unwrap();
+    /// assert_eq!("Apple", active.inner_text());
+    /// ```
+    pub fn active_descendant(&self, control: &Element) -> Option<HtmlElement> {
+        let id = control.get_attribute("aria-activedescendant")?;
+        control
+            .owner_document()?
+            .get_element_by_id(id.trim())?
+            .dyn_into()
+            .ok()
+    }
+
+    /// Asserts that the control has an active descendant with the inner text given.
+    ///
+    /// The leading and trailing whitespace of the inner text is ignored.
+    ///
+    /// # Panics
+    /// If the control has no active descendant or the inner text of the active descendant is
+    /// not equal to `text`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let listbox: HtmlElement = rendered.assert_by_aria_role(AriaRole::ListBox, "Fruit");
+    /// dispatch_key_event(&listbox, KeyEventType::KeyDown, Key::ArrowDown);
+    /// rendered.assert_active_descendant_text(&listbox, "Apple");
+    /// ```
+    pub fn assert_active_descendant_text(&self, control: &Element, text: &str) {
+        match self.active_descendant(control) {
+            Some(active) if active.inner_text().trim() == text.trim() => {}
+            Some(active) => self.pretty_panic(&format!(
+                "expected the active descendant to have the text '{}' but found:\n{}",
+                text,
+                active.outer_html()
+            )),
+            None => self.pretty_panic(&format!(
+                "expected the active descendant to have the text '{}' but found no active \
+                descendant for aria-activedescendant={:?}",
+                text,
+                control.get_attribute("aria-activedescendant")
+            )),
+        }
+    }
+
+    /// Activates the element the way a keyboard user would, based on its explicit role.
+    ///
+    /// Native elements such as `button`, `a` and `input` are activated by a click, which browsers
+    /// also do for Enter or Space. An element made into a button or link with the `role`
+    /// attribute, such as `<div role="button" tabindex="0">`, only gets the behaviour its
+    /// implementation adds, which is often listening for keys rather than relying on a click. The
+    /// first token of the `role` attribute is used:
+    /// - `role="button"` fires a `keydown` [`KeyboardEvent`](web_sys::KeyboardEvent) for Enter,
+    /// then `keydown` and `keyup` for Space, as a button can be activated by either key
+    /// - `role="link"` fires a `keydown` [`KeyboardEvent`](web_sys::KeyboardEvent) for Enter
+    ///
+    /// Any other element is clicked.
+    ///
+    /// _Note: An implementation that handles both Enter and Space is activated twice._
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// // <div role="button" tabindex="0">Save</div>
+    /// let save: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+    /// // Enter and Space are pressed on the div
+    /// rendered.activate(&save);
+    /// ```
+    pub fn activate(&self, element: &HtmlElement) {
+        use crate::event::{dispatch_key_event, Key, KeyEventType};
+
+        let native = matches!(
+            element.tag_name().to_lowercase().as_str(),
+            "a" | "button" | "input" | "summary"
+        );
+        let role = element.get_attribute("role");
+        match role
+            .as_deref()
+            .and_then(|role| role.split_whitespace().next())
+        {
+            Some("button") if !native => {
+                dispatch_key_event(element, KeyEventType::KeyDown, Key::Enter);
+                dispatch_key_event(element, KeyEventType::KeyDown, Key::Lit(' '));
+                dispatch_key_event(element, KeyEventType::KeyUp, Key::Lit(' '));
+            }
+            Some("link") if !native => {
+                dispatch_key_event(element, KeyEventType::KeyDown, Key::Enter)
+            }
+            _ => element.click(),
+        }
+    }
+
+    /// Get the first element that matches the CSS selector, as the type `T`.
+    ///
+    /// This is a lower-level escape hatch for when none of the semantic queries fit. Unlike
+    /// [`query_selector`](web_sys::Element::query_selector), which returns a
+    /// `Result<Option<Element>, JsValue>`, the errors say which selector was used and include the
+    /// formatted HTML of the root element.
+    ///
+    /// Only the first element that matches the selector is checked, so use
+    /// [`get_first_by_selector`](by_selector::BySelector::get_first_by_selector) to find the first
+    /// element of type `T` that matches.
+    ///
+    /// # Errors
+    /// - The selector is not valid.
+    /// - No element matches the selector.
+    /// - The element that matches is not of type `T`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let button: HtmlButtonElement = rendered.find("form > button.primary").unwrap();
+    /// ```
+    pub fn find<T>(&self, css: &str) -> Result<T, Error>
+    where
+        T: JsCast,
+    {
+        let element = self
+            .query_selector(css)
+            .map_err(|_| FindError::SyntaxError(css.to_owned()))?
+            .ok_or_else(|| FindError::NotFound {
+                selector: css.to_owned(),
+                inner_html: self.inner_html(),
+            })?;
+        element.dyn_into::<T>().map_err(|element| {
+            let type_name = std::any::type_name::<T>();
+            Box::new(FindError::WrongType {
+                selector: css.to_owned(),
+                expected: type_name.rsplit("::").next().unwrap_or(type_name),
+                found: element.outer_html(),
+                inner_html: self.inner_html(),
+            }) as Error
+        })
+    }
+
+    /// Removes the root element from the DOM, as the `assert_*` queries do before panicking.
+    ///
+    /// A scoped [`QueryElement`], such as one from [`within`](QueryElement::within), is part of
+    /// the DOM of another root, so nothing is removed and only the owning root removes it.
+    pub fn remove(&self) {
+        if !self.scoped {
+            self.root.remove();
+        }
+    }
+
+    /// Panics with the message given followed by the formatted HTML of the root element, in the
+    /// same way as the built-in asserts.
+    ///
+    /// Use this in custom assertions so a failure shows the HTML that was being tested. The
+    /// root element is removed before panicking, like the `assert_*` queries, unless this is a
+    /// scoped [`QueryElement`].
+    ///
+    /// See [`pretty_assert`](crate::pretty_assert) for an assert macro using this.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let items = rendered.assert_all_by_selector::<HtmlElement>("li");
+    /// if items.count() % 2 != 0 {
+    ///     rendered.pretty_panic("expected an even number of items");
+    /// }
+    /// ```
+    pub fn pretty_panic(&self, msg: &str) -> ! {
+        let inner_html = self.inner_html();
+        self.remove();
+        panic!(
+            "\n{}\n\nIn the following HTML:{}",
+            msg,
+            hyphae_utils::format_html(&inner_html)
+        )
+    }
+
+    /// The size and position of the element relative to the viewport.
+    ///
+    /// This is [`Element::get_bounding_client_rect`], useful for layout tests such as checking
+    /// that two elements don't overlap.
+    pub fn bounding_rect(&self, element: &Element) -> DomRect {
+        element.get_bounding_client_rect()
+    }
+
+    /// Asserts that the element is entirely within the viewport of the window.
+    ///
+    /// Useful for checking that an element is actually on screen, such as an error message that
+    /// should be shown to the user without scrolling.
+    ///
+    /// # Panics
+    /// If the element is not entirely within the viewport or has no size, with the rect of the
+    /// element and the size of the viewport in the message.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let error: HtmlElement = rendered.assert_by_aria_role(AriaRole::Alert, "Invalid email");
+    /// rendered.assert_visible_in_viewport(&error);
+    /// ```
+    pub fn assert_visible_in_viewport(&self, element: &Element) {
+        let rect = self.bounding_rect(element);
+        let window = web_sys::window().expect("No global window");
+        let width = window
+            .inner_width()
+            .ok()
+            .and_then(|w| w.as_f64())
+            .unwrap_or_default();
+        let height = window
+            .inner_height()
+            .ok()
+            .and_then(|h| h.as_f64())
+            .unwrap_or_default();
 
-/// Wrapper around a root element which has been rendered.
-pub struct QueryElement(HtmlElement);
+        let has_size = rect.width() > 0.0 && rect.height() > 0.0;
+        let in_viewport = rect.left() >= 0.0
+            && rect.top() >= 0.0
+            && rect.right() <= width
+            && rect.bottom() <= height;
+        if !has_size || !in_viewport {
+            self.pretty_panic(&format!(
+                "expected element to be visible in the viewport ({}x{}) but found it at \
+                (left: {}, top: {}, width: {}, height: {}):\n{}",
+                width,
+                height,
+                rect.left(),
+                rect.top(),
+                rect.width(),
+                rect.height(),
+                element.outer_html()
+            ));
+        }
+    }
 
-impl QueryElement {
-    /// Wrap rendered root element ready to be queried.
+    /// Perform an action and await a change in the root element, such as an element being added
+    /// or an attribute changing.
+    ///
+    /// This is [`effect_dom`](hyphae_utils::effect_dom) observing the root element, so changes
+    /// outside of the root element are ignored.
+    ///
+    /// # Panics
+    /// If the root element does not change within the `timeout`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # async fn effect_example() {
+    /// use std::time::Duration;
+    /// use hyphae::prelude::*;
+    /// use web_sys::{HtmlButtonElement, HtmlElement};
+    ///
+    /// let rendered = QueryElement::new();
+    /// let fetch: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Fetch");
+    /// rendered.effect(move || fetch.click(), Duration::from_millis(100)).await;
+    /// rendered.assert_by_text::<HtmlElement>("42 items");
+    /// # }
+    /// ```
+    pub async fn effect<F>(&self, action: F, timeout: Duration)
+    where
+        F: Fn() + 'static,
+    {
+        hyphae_utils::effect_dom(self.root.as_ref(), action, timeout).await;
+    }
+
+    /// Perform the interactions in `f` and await the updates they scheduled to be applied.
+    ///
+    /// Modelled on `act` from React Testing Library, after `f` has run this awaits
+    /// [`flush_updates`](hyphae_utils::flush_updates) so that renders scheduled in a microtask or
+    /// an animation frame are in the DOM before asserting. Unlike
+    /// [`effect`](QueryElement::effect) this does not require the DOM to change.
     ///
     /// # Examples
     /// ```no_run
+    /// # async fn act_example() {
     /// use hyphae::prelude::*;
+    /// use web_sys::{HtmlButtonElement, HtmlElement};
+    ///
     /// let rendered = QueryElement::new();
-    /// // .. use `rendered` to get elements and perform tests
+    /// let increment: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "+1");
+    /// rendered.act(|| increment.click()).await;
+    /// rendered.assert_by_text::<HtmlElement>("Count: 1");
+    /// # }
     /// ```
-    pub fn new() -> Self {
-        Self::default()
+    pub async fn act<F>(&self, f: F)
+    where
+        F: FnOnce(),
+    {
+        f();
+        hyphae_utils::flush_updates().await;
+    }
+
+    /// Perform an action and await an element with the text given to be in the root element.
+    ///
+    /// [`effect_dom`](hyphae_utils::effect_dom) completes on the first change in the DOM, which
+    /// can be a loading indicator rather than the result you want to assert. This completes only
+    /// once [`get_by_text`](by_text::ByText::get_by_text) would find an element with the `text`,
+    /// checking after the action and after every change in the root element.
+    ///
+    /// # Panics
+    /// If no element with the `text` is found within the `timeout`, with the same message as
+    /// [`assert_by_text`](by_text::ByText::assert_by_text).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # async fn effect_until_text_example() {
+    /// use std::time::Duration;
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// let fetch: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Fetch");
+    /// rendered
+    ///     .effect_until_text(move || fetch.click(), "42 items", Duration::from_millis(500))
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn effect_until_text<F>(&self, action: F, text: &str, timeout: Duration)
+    where
+        F: Fn() + 'static,
+    {
+        let scope = self.within(&self.root);
+        let search = text.to_owned();
+        let condition = move || scope.get_by_text::<Element>(&search).is_ok();
+        let result =
+            hyphae_utils::effect_dom_until(self.root.as_ref(), action, condition, timeout).await;
+        if result.is_err() {
+            self.assert_by_text::<Element>(text);
+        }
+    }
+
+    /// Start logging the events fired within the root element.
+    ///
+    /// A capturing listener is installed on the root element for common event types, such as
+    /// `click`, `input` and `keydown`, so events are logged even when a listener stops them from
+    /// propagating. Use [`events`](QueryElement::events) to get the trace, which is useful to
+    /// find out which interactions actually fired when a test isn't behaving as expected.
+    ///
+    /// Calling `trace` again clears the events logged so far.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use web_sys::HtmlButtonElement;
+    ///
+    /// let rendered = QueryElement::new();
+    /// rendered.trace();
+    /// let save: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+    /// save.click();
+    /// println!("{:#?}", rendered.events());
+    /// ```
+    pub fn trace(&self) {
+        // drop the previous trace first to remove the listeners
+        self.trace.replace(None);
+        self.trace
+            .replace(Some(trace::EventTrace::install(&self.root)));
+    }
+
+    /// The events logged since [`trace`](QueryElement::trace) was called, in the order they were
+    /// fired.
+    ///
+    /// Returns an empty [`Vec`] when `trace` has not been called.
+    pub fn events(&self) -> Vec<TracedEvent> {
+        self.trace
+            .borrow()
+            .as_ref()
+            .map(|trace| trace.events())
+            .unwrap_or_default()
+    }
+
+    /// Register a closure to run when this [`QueryElement`] is dropped.
+    ///
+    /// Removing the root element doesn't stop everything a framework has started, such as event
+    /// listeners on the window, timers or the framework runtime, and these can affect the tests that
+    /// follow. Register the framework specific teardown here so it is done in one place.
+    ///
+    /// The closures run in the reverse order of registration, before the root element is
+    /// removed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    ///
+    /// let rendered = QueryElement::new();
+    /// rendered.on_cleanup(|| {
+    ///     let storage = web_sys::window().unwrap().local_storage().unwrap().unwrap();
+    ///     storage.clear().unwrap();
+    /// });
+    /// ```
+    pub fn on_cleanup<F>(&self, cleanup: F)
+    where
+        F: FnOnce() + 'static,
+    {
+        self.cleanups.borrow_mut().push(Box::new(cleanup));
+    }
+
+    /// Scope queries to the body of a same-origin iframe.
+    ///
+    /// The scoped [`QueryElement`] does not remove the body of the iframe when dropped.
+    ///
+    /// Elements in an iframe belong to the iframe window and not the window of the test, so
+    /// will not pass the checks against types such as [`HtmlElement`]. Use
+    /// [`JsValue`](wasm_bindgen::JsValue) as the element type for queries and then use
+    /// [`unchecked_into`](JsCast::unchecked_into) for the type you want.
+    ///
+    /// # Errors
+    /// If the iframe is cross-origin or the iframe document has no body, for example, when it
+    /// has not loaded.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use hyphae::prelude::*;
+    /// use wasm_bindgen::{JsCast, JsValue};
+    /// use web_sys::{HtmlElement, HtmlIFrameElement};
+    ///
+    /// let rendered = QueryElement::new();
+    /// let iframe: HtmlIFrameElement = rendered.assert_first_by_selector("iframe");
+    /// let frame = QueryElement::from_iframe(&iframe).unwrap();
+    /// let heading: HtmlElement = frame
+    ///     .assert_by_aria_role::<JsValue>(AriaRole::Heading, "Micro frontend")
+    ///     .unchecked_into();
+    /// ```
+    pub fn from_iframe(iframe: &HtmlIFrameElement) -> Result<QueryElement, Error> {
+        let document = iframe
+            .content_document()
+            .ok_or(ScopeError::NoContentDocument)?;
+        let body = document.body().ok_or(ScopeError::NoBody)?;
+        Ok(Self {
+            root: body,
+            scoped: true,
+            trace: RefCell::default(),
+            cleanups: RefCell::default(),
+        })
     }
 }
 
+#[cfg(feature = "storage")]
+fn local_storage() -> web_sys::Storage {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .expect("Cannot get local storage")
+}
+
+#[cfg(feature = "storage")]
+fn storage_keys(storage: &web_sys::Storage) -> Vec<String> {
+    let length = storage.length().unwrap_or_default();
+    (0..length)
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .collect()
+}
+
 impl Default for QueryElement {
     fn default() -> Self {
         let doc = web_sys::window()
@@ -44,13 +1242,18 @@ impl Default for QueryElement {
             .append_child(&div)
             .expect("Unable to append test div to body");
 
-        Self(div.unchecked_into())
+        div.unchecked_into::<HtmlElement>().into()
     }
 }
 
 impl From<HtmlElement> for QueryElement {
     fn from(root_element: HtmlElement) -> Self {
-        Self(root_element)
+        Self {
+            root: root_element,
+            scoped: false,
+            trace: RefCell::default(),
+            cleanups: RefCell::default(),
+        }
     }
 }
 
@@ -58,13 +1261,13 @@ impl Deref for QueryElement {
     type Target = HtmlElement;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.root
     }
 }
 
 impl AsRef<HtmlElement> for QueryElement {
     fn as_ref(&self) -> &HtmlElement {
-        &self.0
+        &self.root
     }
 }
 
@@ -73,6 +1276,1004 @@ impl AsRef<HtmlElement> for QueryElement {
 // user is performing wasm-pack test without --headless.
 impl Drop for QueryElement {
     fn drop(&mut self) {
-        self.0.remove();
+        for cleanup in self.cleanups.take().into_iter().rev() {
+            cleanup();
+        }
+        self.remove();
+    }
+}
+
+/// The form that a submit `button` or `input` element submits, if it is one.
+fn submit_button_form(element: &HtmlElement) -> Option<HtmlFormElement> {
+    if let Some(button) = element.dyn_ref::<HtmlButtonElement>() {
+        if button.type_() == "submit" {
+            return button.form();
+        }
+    } else if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+        if input.type_() == "submit" {
+            return input.form();
+        }
+    }
+    None
+}
+
+/// An error indicating that no element of the expected type matches the CSS selector.
+enum FindError {
+    SyntaxError(String),
+    NotFound {
+        selector: String,
+        inner_html: String,
+    },
+    WrongType {
+        selector: String,
+        expected: &'static str,
+        found: String,
+        inner_html: String,
+    },
+}
+
+impl Debug for FindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindError::SyntaxError(selector) => {
+                write!(f, "\nSelector string of '{}' syntax is not valid!", selector)
+            }
+            FindError::NotFound {
+                selector,
+                inner_html,
+            } => write!(
+                f,
+                "\nNo element found that matches the selector '{}' in the following HTML:{}",
+                selector,
+                hyphae_utils::format_html(inner_html)
+            ),
+            FindError::WrongType {
+                selector,
+                expected,
+                found,
+                inner_html,
+            } => write!(
+                f,
+                "\nThe element that matches the selector '{}' is not a {}:\n{}\n\nIn the following HTML:{}",
+                selector,
+                expected,
+                found,
+                hyphae_utils::format_html(inner_html)
+            ),
+        }
+    }
+}
+
+impl Display for FindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for FindError {}
+
+/// An error indicating that the element to scope queries to could not be found.
+enum ScopeError {
+    NoContentDocument,
+    NoBody,
+    ArticleNotFound {
+        heading_name: String,
+        inner_html: String,
+    },
+    MainNotFound {
+        inner_html: String,
+    },
+    MultipleMain {
+        inner_html: String,
+    },
+}
+
+impl Debug for ScopeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScopeError::NoContentDocument => write!(
+                f,
+                "\nCannot access the document of the iframe, is the iframe cross-origin?"
+            ),
+            ScopeError::NoBody => write!(
+                f,
+                "\nThe document of the iframe has no body, has the iframe loaded?"
+            ),
+            ScopeError::ArticleNotFound {
+                heading_name,
+                inner_html,
+            } => write!(
+                f,
+                "\nNo article found with a heading named '{}' in the following HTML:{}",
+                heading_name,
+                hyphae_utils::format_html(inner_html)
+            ),
+            ScopeError::MainNotFound { inner_html } => write!(
+                f,
+                "\nNo main landmark found in the following HTML:{}",
+                hyphae_utils::format_html(inner_html)
+            ),
+            ScopeError::MultipleMain { inner_html } => write!(
+                f,
+                "\nMore than one main landmark found in the following HTML:{}",
+                hyphae_utils::format_html(inner_html)
+            ),
+        }
+    }
+}
+
+impl Display for ScopeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ScopeError {}
+
+struct AriaRefError {
+    dangling: Vec<String>,
+    inner_html: String,
+}
+
+impl Debug for AriaRefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\nFound {} ARIA reference(s) to an id that does not exist:\n{}\n\nIn the following HTML:{}",
+            self.dangling.len(),
+            self.dangling
+                .iter()
+                .map(|reference| format!("  {}", reference))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            hyphae_utils::format_html(&self.inner_html)
+        )
+    }
+}
+
+impl Display for AriaRefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for AriaRefError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae::prelude::*;
+    use hyphae_utils::make_element_with_html_string;
+
+    use std::rc::Rc;
+
+    use wasm_bindgen::{prelude::Closure, JsValue};
+    use web_sys::HtmlButtonElement;
+
+    #[wasm_bindgen_test]
+    fn scoped_query_errors_only_show_subtree_html() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <section id="first">
+                <button>Ok</button>
+            </section>
+            <section id="second">
+                <button>Cancel</button>
+            </section>
+        "#,
+        )
+        .into();
+
+        let second: HtmlElement = rendered.assert_first_by_selector("#second");
+        let scoped = rendered.within(&second);
+
+        let result = scoped.get_by_aria_role::<HtmlButtonElement>(AriaRole::Button, "Ok");
+
+        match result {
+            Ok(_) => panic!("Should not have found the 'Ok' button outside of the scoped element"),
+            Err(error) => {
+                let expected = format!(
+                    "\nNo element found with an accessible name equal or similar to '{}' in the following HTML:{}",
+                    "Ok",
+                    r#"
+<button>Cancel</button>
+"#
+                );
+                assert_eq!(expected, format!("{:?}", error));
+            }
+        }
+
+        // a failed scoped assert removes the root, which leaves the scoped element in place
+        scoped.remove();
+        assert!(second.is_connected());
+
+        drop(scoped);
+        // dropping the scoped element doesn't remove it
+        assert!(second.is_connected());
+    }
+
+    #[wasm_bindgen_test]
+    fn query_heading_within_iframe() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <iframe id="micro-frontend"></iframe>
+        "#,
+        )
+        .into();
+
+        let iframe: HtmlIFrameElement = rendered.assert_first_by_selector("#micro-frontend");
+        let body = iframe.content_document().unwrap().body().unwrap();
+        body.set_inner_html(r#"<h1 id="heading">Micro frontend</h1>"#);
+
+        let frame = QueryElement::from_iframe(&iframe).unwrap();
+        let heading: HtmlElement = frame
+            .get_by_aria_role::<JsValue>(AriaRole::Heading, "Micro frontend")
+            .unwrap()
+            .unchecked_into();
+        assert_eq!("heading", heading.id());
+
+        drop(frame);
+        // dropping the scoped iframe body doesn't remove it
+        assert!(body.is_connected());
+    }
+
+    #[wasm_bindgen_test]
+    fn dropdown_closes_after_click_outside() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <div id="dropdown" class="open">
+                <button>Menu</button>
+            </div>
+        "#,
+        )
+        .into();
+
+        let dropdown: HtmlElement = rendered.assert_first_by_selector("#dropdown");
+        let button: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Menu");
+        button.focus().unwrap();
+
+        let listener = {
+            let dropdown = dropdown.clone();
+            Closure::<dyn Fn(MouseEvent)>::wrap(Box::new(move |e: MouseEvent| {
+                let target = e.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+                if !dropdown.contains(target.as_ref()) {
+                    dropdown.set_class_name("");
+                }
+            }))
+        };
+        let document = web_sys::window().unwrap().document().unwrap();
+        document
+            .add_event_listener_with_callback("mousedown", listener.as_ref().unchecked_ref())
+            .unwrap();
+
+        // pressing inside the dropdown keeps it open
+        let mut event_init = MouseEventInit::new();
+        event_init.bubbles(true);
+        button
+            .dispatch_event(
+                &MouseEvent::new_with_mouse_event_init_dict("mousedown", &event_init).unwrap(),
+            )
+            .unwrap();
+        assert_has_class!(dropdown, "open");
+
+        rendered.click_outside();
+        assert_not_class!(dropdown, "open");
+        assert_ne!(
+            Some(button.unchecked_into::<web_sys::Element>()),
+            document.active_element()
+        );
+
+        document
+            .remove_event_listener_with_callback("mousedown", listener.as_ref().unchecked_ref())
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn render_html_fragment_and_query() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <ul>
+                <li>Gardening</li>
+            </ul>
+        "#,
+        );
+
+        assert_eq!("<ul><li>Gardening</li></ul>", rendered.inner_html());
+        let item: HtmlElement = rendered.assert_by_aria_role(AriaRole::ListItem, "Gardening");
+
+        drop(rendered);
+        assert!(!item.is_connected());
+    }
+
+    #[hyphae_test_macros::hyphae_test]
+    fn hyphae_test_injects_query_element(rendered: QueryElement) {
+        rendered.set_inner_html("<button>Ok</button>");
+
+        let ok: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Ok");
+        assert!(ok.is_connected());
+    }
+
+    #[wasm_bindgen_test]
+    fn trace_logs_click_on_button() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <form>
+                <button id="save" type="button">Save</button>
+            </form>
+        "#,
+        );
+
+        let save: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+        save.click();
+        assert!(rendered.events().is_empty());
+
+        rendered.trace();
+        save.click();
+
+        assert_eq!(
+            vec![TracedEvent {
+                event_type: "click".to_owned(),
+                target: "form > button#save".to_owned(),
+            }],
+            rendered.events()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn within_article_by_heading() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <article>
+                <h2>Release notes</h2>
+                <button id="share-notes">Share</button>
+            </article>
+            <article>
+                <h2>Roadmap</h2>
+                <button id="share-roadmap">Share</button>
+            </article>
+        "#,
+        );
+
+        let roadmap = rendered.within_article("Roadmap").unwrap();
+        let share: HtmlButtonElement = roadmap.assert_by_aria_role(AriaRole::Button, "Share");
+        assert_eq!("share-roadmap", share.id());
+
+        assert!(rendered.within_article("Blog").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn cleanups_run_on_drop() {
+        let rendered = QueryElement::render_html("<p>App</p>");
+        let root: HtmlElement = rendered.clone();
+        let ran = Rc::new(RefCell::new(Vec::new()));
+
+        let first = Rc::clone(&ran);
+        rendered.on_cleanup(move || first.borrow_mut().push("stop app"));
+        let second = Rc::clone(&ran);
+        rendered.on_cleanup(move || {
+            // the root is still in the DOM so the app can be unmounted from it
+            assert!(root.is_connected());
+            second.borrow_mut().push("clear storage")
+        });
+        assert!(ran.borrow().is_empty());
+
+        drop(rendered);
+        assert_eq!(vec!["clear storage", "stop app"], *ran.borrow());
+    }
+
+    #[wasm_bindgen_test]
+    fn column_header_within_table() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <table>
+                <caption>Invoices</caption>
+                <thead>
+                    <tr><th id="invoice-date">Date</th><th id="invoice-amount">Amount</th></tr>
+                </thead>
+            </table>
+            <table>
+                <caption>Refunds</caption>
+                <thead>
+                    <tr><th id="refund-date">Date</th><th id="refund-amount">Amount</th></tr>
+                </thead>
+            </table>
+        "#,
+        );
+
+        let amount: HtmlElement = rendered
+            .within_table("Refunds")
+            .unwrap()
+            .assert_by_aria_role(AriaRole::ColumnHeader, "Amount");
+        assert_eq!("refund-amount", amount.id());
+
+        assert!(rendered.within_table("Payments").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn interactive_elements_with_roles_and_names() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <header>
+                <h1>todos</h1>
+                <input class="new-todo" placeholder="What needs to be done?" />
+            </header>
+            <ul>
+                <li>
+                    <input id="item-check" type="checkbox" />
+                    <label for="item-check">Gardening</label>
+                    <button class="destroy"></button>
+                </li>
+            </ul>
+            <a href="/#/active">Active</a>
+            <button hidden>Hidden</button>
+        "#,
+        );
+
+        let elements: Vec<(AriaRole, String)> = rendered
+            .interactive_elements()
+            .into_iter()
+            .map(|(role, name, _)| (role, name))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (AriaRole::TextBox, "What needs to be done?".to_owned()),
+                (AriaRole::Checkbox, "Gardening".to_owned()),
+                (AriaRole::Button, String::new()),
+                (AriaRole::Link, "Active".to_owned()),
+            ],
+            elements
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn effect_waits_for_async_update_of_root() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <button>Refresh</button>
+            <p id="status">Stale</p>
+        "#,
+        );
+
+        let refresh: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Refresh");
+        let status: HtmlElement = rendered.assert_first_by_selector("#status");
+
+        let on_click = {
+            let status = status.clone();
+            Closure::wrap(Box::new(move || {
+                let status = status.clone();
+                let refreshed = Closure::once_into_js(move || status.set_inner_text("Fresh"));
+                web_sys::window()
+                    .unwrap()
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        refreshed.unchecked_ref(),
+                        20,
+                    )
+                    .unwrap();
+            }) as Box<dyn Fn()>)
+        };
+        refresh
+            .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+            .unwrap();
+
+        assert_eq!("Stale", status.inner_text());
+        rendered
+            .effect(move || refresh.click(), Duration::from_millis(500))
+            .await;
+        assert_eq!("Fresh", status.inner_text());
+    }
+
+    #[wasm_bindgen_test]
+    async fn act_applies_update_scheduled_for_next_frame() {
+        let rendered = QueryElement::render_html(
+            r#"<button id="increment">+1</button><span id="count">Count: 0</span>"#,
+        );
+        let button: HtmlButtonElement = rendered.assert_first_by_selector("#increment");
+        let count: HtmlElement = rendered.assert_first_by_selector("#count");
+
+        let render = Closure::wrap(Box::new(move || {
+            count.set_text_content(Some("Count: 1"));
+        }) as Box<dyn Fn()>);
+        let on_click = Closure::wrap(Box::new(move || {
+            // like a framework, render in the next animation frame rather than straight away
+            web_sys::window()
+                .unwrap()
+                .request_animation_frame(render.as_ref().unchecked_ref())
+                .unwrap();
+        }) as Box<dyn Fn()>);
+        button.set_onclick(Some(on_click.as_ref().unchecked_ref()));
+
+        button.click();
+        assert!(rendered.get_by_text::<HtmlElement>("Count: 1").is_err());
+
+        rendered.act(|| button.click()).await;
+        rendered.assert_by_text::<HtmlElement>("Count: 1");
+
+        button.set_onclick(None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn effect_until_text_waits_for_result() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <button>Fetch</button>
+            <p id="result"></p>
+        "#,
+        );
+
+        let fetch: HtmlButtonElement = rendered.assert_by_aria_role(AriaRole::Button, "Fetch");
+        let result: HtmlElement = rendered.assert_first_by_selector("#result");
+
+        let on_click = {
+            let result = result.clone();
+            Closure::wrap(Box::new(move || {
+                // a loading state is shown before the fetched result
+                result.set_inner_text("Loading...");
+                let result = result.clone();
+                let fetched = Closure::once_into_js(move || result.set_inner_text("42 items"));
+                web_sys::window()
+                    .unwrap()
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        fetched.unchecked_ref(),
+                        20,
+                    )
+                    .unwrap();
+            }) as Box<dyn Fn()>)
+        };
+        fetch
+            .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+            .unwrap();
+
+        rendered
+            .effect_until_text(
+                move || fetch.click(),
+                "42 items",
+                Duration::from_millis(500),
+            )
+            .await;
+        assert_eq!("42 items", result.inner_text());
+    }
+
+    #[wasm_bindgen_test]
+    fn isolated_roots_have_unique_ids() {
+        let first = QueryElement::isolated();
+        let second = QueryElement::isolated();
+        assert_ne!(first.id(), second.id());
+        assert!(first.id().starts_with("hyphae-test-app-"));
+    }
+
+    #[cfg(feature = "storage")]
+    #[wasm_bindgen_test]
+    fn isolated_roots_do_not_share_storage() {
+        let storage = local_storage();
+        let first = QueryElement::isolated();
+        let second = QueryElement::isolated();
+
+        let first_key = first.storage_key("todos");
+        let second_key = second.storage_key("todos");
+        assert_ne!(first_key, second_key);
+
+        storage.set_item(&first_key, "[\"Gardening\"]").unwrap();
+        assert_eq!(None, storage.get_item(&second_key).unwrap());
+        storage.set_item(&second_key, "[\"Cooking\"]").unwrap();
+        storage.set_item("hyphae-unrelated", "kept").unwrap();
+
+        drop(first);
+        assert_eq!(None, storage.get_item(&first_key).unwrap());
+        assert_eq!(
+            Some("[\"Cooking\"]".to_owned()),
+            storage.get_item(&second_key).unwrap()
+        );
+        assert_eq!(
+            Some("kept".to_owned()),
+            storage.get_item("hyphae-unrelated").unwrap()
+        );
+
+        drop(second);
+        assert_eq!(None, storage.get_item(&second_key).unwrap());
+        storage.remove_item("hyphae-unrelated").unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn scope_to_main_landmark() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <header>
+                <a href="/">Home</a>
+            </header>
+            <main>
+                <a href="/reports">Reports</a>
+            </main>
+        "#,
+        );
+
+        let main = rendered.main().unwrap();
+        assert!(main
+            .get_by_aria_role::<HtmlElement>(AriaRole::Link, "Home")
+            .is_err());
+        main.assert_by_aria_role::<HtmlElement>(AriaRole::Link, "Reports");
+        drop(main);
+
+        rendered.set_inner_html("<main></main><div role=\"main\"></div>");
+        assert!(rendered.main().is_err());
+
+        rendered.set_inner_html("<p>No landmarks</p>");
+        assert!(rendered.main().is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn enumerate_menu_and_dialog_popup_triggers() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button aria-haspopup="menu">File</button>
+            <button aria-haspopup="true">Edit</button>
+            <button aria-haspopup="dialog">Settings</button>
+            <button aria-haspopup="false">Help</button>
+            <button>Save</button>
+        "#,
+        )
+        .into();
+
+        let names = |popup| {
+            rendered
+                .get_all_popup_triggers(popup)
+                .map(|trigger| trigger.inner_text())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["File", "Edit", "Settings"], names(None));
+        assert_eq!(vec!["File", "Edit"], names(Some(HasPopupToken::Menu)));
+        assert_eq!(vec!["File", "Edit"], names(Some(HasPopupToken::True)));
+        assert_eq!(vec!["Settings"], names(Some(HasPopupToken::Dialog)));
+        assert!(names(Some(HasPopupToken::ListBox)).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn aria_refs_valid_when_every_id_exists() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <h2 id="settings-heading">Settings</h2>
+            <p id="settings-hint">Changes are saved automatically</p>
+            <button aria-controls="settings-panel" aria-expanded="true">Toggle</button>
+            <section id="settings-panel" aria-labelledby="settings-heading" aria-describedby="settings-hint">
+                <input aria-labelledby="settings-heading settings-hint" />
+            </section>
+        "#,
+        )
+        .into();
+
+        rendered.assert_aria_refs_valid();
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(expected = "aria-controls=\"missing-panel\" on <button")]
+    fn aria_refs_valid_panics_with_dangling_controls() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <button aria-controls="missing-panel" aria-expanded="false">Toggle</button>
+            <section id="panel" hidden>Content</section>
+        "#,
+        )
+        .into();
+
+        rendered.assert_aria_refs_valid();
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(
+        expected = "expected a total of 4\n\nIn the following HTML:\n<p id=\"total\">Total: 3</p>"
+    )]
+    fn pretty_panic_shows_message_and_html() {
+        let rendered: QueryElement =
+            make_element_with_html_string(r#"<p id="total">Total: 3</p>"#).into();
+
+        rendered.pretty_panic("expected a total of 4");
+    }
+
+    #[wasm_bindgen_test]
+    fn activate_div_button_with_space() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <div role="button" tabindex="0">Save</div>
+            <p id="status">Unsaved</p>
+        "#,
+        );
+        let save: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Save");
+        let status: HtmlElement = rendered.assert_first_by_selector("#status");
+
+        let on_keyup = {
+            let status = status.clone();
+            Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                if e.key() == " " {
+                    status.set_inner_text("Saved");
+                }
+            }) as Box<dyn Fn(web_sys::KeyboardEvent)>)
+        };
+        let on_click = {
+            let status = status.clone();
+            Closure::wrap(Box::new(move || status.set_inner_text("Clicked")) as Box<dyn Fn()>)
+        };
+        save.add_event_listener_with_callback("keyup", on_keyup.as_ref().unchecked_ref())
+            .unwrap();
+        save.add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+            .unwrap();
+
+        rendered.activate(&save);
+        assert_eq!("Saved", status.inner_text());
+
+        let native = QueryElement::render_html(r#"<button id="native">Native</button>"#);
+        let button: HtmlElement = native.assert_by_aria_role(AriaRole::Button, "Native");
+        button
+            .add_event_listener_with_callback("click", on_click.as_ref().unchecked_ref())
+            .unwrap();
+        native.activate(&button);
+        assert_eq!("Clicked", status.inner_text());
+    }
+
+    #[wasm_bindgen_test]
+    fn activate_div_button_with_enter_and_first_role_token() {
+        let rendered =
+            QueryElement::render_html(r#"<span role="button switch" tabindex="0">Mute</span>"#);
+        let mute: HtmlElement = rendered.assert_first_by_selector("span");
+
+        let on_keydown = {
+            let mute = mute.clone();
+            Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                if e.key() == "Enter" {
+                    mute.set_inner_text("Unmute");
+                }
+            }) as Box<dyn Fn(web_sys::KeyboardEvent)>)
+        };
+        mute.add_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref())
+            .unwrap();
+
+        rendered.activate(&mute);
+        assert_eq!("Unmute", mute.inner_text());
+    }
+
+    #[wasm_bindgen_test]
+    fn arrow_down_moves_active_descendant_of_listbox() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <ul role="listbox" aria-label="Fruit" tabindex="0">
+                <li id="apple" role="option">Apple</li>
+                <li id="banana" role="option">Banana</li>
+                <li id="cherry" role="option">Cherry</li>
+            </ul>
+        "#,
+        );
+        let listbox: HtmlElement = rendered.assert_by_aria_role(AriaRole::ListBox, "Fruit");
+        assert!(rendered.active_descendant(&listbox).is_none());
+
+        let on_keydown = {
+            let listbox = listbox.clone();
+            Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                if e.key() != "ArrowDown" {
+                    return;
+                }
+                let ids = ["apple", "banana", "cherry"];
+                let next = match listbox.get_attribute("aria-activedescendant") {
+                    Some(id) => ids.iter().position(|i| *i == id).map_or(0, |i| i + 1),
+                    None => 0,
+                };
+                if let Some(id) = ids.get(next) {
+                    listbox.set_attribute("aria-activedescendant", id).unwrap();
+                }
+            }) as Box<dyn Fn(web_sys::KeyboardEvent)>)
+        };
+        listbox
+            .add_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref())
+            .unwrap();
+
+        dispatch_key_event(&listbox, KeyEventType::KeyDown, Key::ArrowDown);
+        rendered.assert_active_descendant_text(&listbox, "Apple");
+
+        dispatch_key_event(&listbox, KeyEventType::KeyDown, Key::ArrowDown);
+        let active = rendered.active_descendant(&listbox).unwrap();
+        assert_eq!("banana", active.id());
+        rendered.assert_active_descendant_text(&listbox, "Banana");
+
+        listbox
+            .remove_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref())
+            .unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn find_by_css_selector() {
+        let rendered = QueryElement::render_html(
+            r#"<form><button class="primary">Save</button><button>Cancel</button></form>"#,
+        );
+
+        let save: HtmlButtonElement = rendered.find("form > button.primary").unwrap();
+        assert_eq!("Save", save.inner_text());
+
+        let error = rendered.find::<HtmlElement>("button.danger").unwrap_err();
+        assert!(format!("{:?}", error)
+            .starts_with("\nNo element found that matches the selector 'button.danger'"));
+
+        let error = rendered.find::<HtmlInputElement>("button").unwrap_err();
+        assert!(format!("{:?}", error).starts_with(
+            "\nThe element that matches the selector 'button' is not a HtmlInputElement:\n\
+            <button class=\"primary\">Save</button>"
+        ));
+
+        assert!(rendered.find::<HtmlElement>("button[").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn bounding_rect_of_visible_element() {
+        let rendered = QueryElement::render_html(
+            r#"<p role="alert" style="position: fixed; top: 0; left: 0; width: 120px; height: 40px; margin: 0">Invalid email</p>"#,
+        );
+
+        let error: HtmlElement = rendered.assert_by_aria_role(AriaRole::Alert, "Invalid email");
+        let rect = rendered.bounding_rect(&error);
+        assert_eq!(0.0, rect.top());
+        assert_eq!(0.0, rect.left());
+        assert_eq!(120.0, rect.width());
+        assert_eq!(40.0, rect.height());
+
+        rendered.assert_visible_in_viewport(&error);
+    }
+
+    #[wasm_bindgen_test]
+    #[should_panic(expected = "expected element to be visible in the viewport")]
+    fn element_above_viewport_is_not_visible() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"<p id="error" style="position: fixed; top: -200px">Invalid email</p>"#,
+        )
+        .into();
+
+        let error: HtmlElement = rendered.assert_first_by_selector("#error");
+        rendered.assert_visible_in_viewport(&error);
+    }
+
+    #[wasm_bindgen_test]
+    fn click_submit_runs_form_onsubmit_once() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <form aria-label="Sign up">
+                <input type="email" aria-label="Email" />
+                <button type="submit">Sign up</button>
+                <button type="button">Cancel</button>
+            </form>
+            <p id="submissions">0</p>
+        "#,
+        );
+
+        let form: HtmlFormElement = rendered.assert_first_by_selector("form");
+        let submissions: HtmlElement = rendered.assert_first_by_selector("#submissions");
+
+        let on_submit = {
+            let submissions = submissions.clone();
+            Closure::wrap(Box::new(move |e: web_sys::Event| {
+                // stop the test page from navigating
+                e.prevent_default();
+                let count: u32 = submissions.inner_text().parse().unwrap();
+                submissions.set_inner_text(&(count + 1).to_string());
+            }) as Box<dyn Fn(web_sys::Event)>)
+        };
+        form.set_onsubmit(Some(on_submit.as_ref().unchecked_ref()));
+
+        let cancel: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Cancel");
+        rendered.click_submit(&cancel);
+        assert_eq!("0", submissions.inner_text());
+
+        let submit: HtmlElement = rendered.assert_by_aria_role(AriaRole::Button, "Sign up");
+        rendered.click_submit(&submit);
+        assert_eq!("1", submissions.inner_text());
+
+        // without the default action of the click the form is submitted by the fallback
+        let prevent_click = Closure::wrap(
+            Box::new(|e: web_sys::Event| e.prevent_default()) as Box<dyn Fn(web_sys::Event)>
+        );
+        submit
+            .add_event_listener_with_callback("click", prevent_click.as_ref().unchecked_ref())
+            .unwrap();
+        rendered.click_submit(&submit);
+        assert_eq!("2", submissions.inner_text());
+
+        submit
+            .remove_event_listener_with_callback("click", prevent_click.as_ref().unchecked_ref())
+            .unwrap();
+        form.set_onsubmit(None);
+    }
+
+    #[wasm_bindgen_test]
+    fn enumerate_rows_of_grid() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <table aria-label="Files">
+                <tr><td>ignored.txt</td></tr>
+            </table>
+            <div role="treegrid" aria-label="Files">
+                <div role="row"><div role="gridcell">src</div></div>
+            </div>
+            <div role="grid" aria-label="Files">
+                <div role="row">
+                    <div role="columnheader">Name</div>
+                    <div role="columnheader">Size</div>
+                </div>
+                <div role="row">
+                    <div role="gridcell">report.pdf</div>
+                    <div role="gridcell">2 MB</div>
+                </div>
+                <div role="row">
+                    <div role="gridcell">notes.md</div>
+                    <div role="gridcell">4 KB</div>
+                </div>
+            </div>
+        "#,
+        );
+
+        let grid = rendered.within_grid("Files").unwrap();
+        assert_eq!(Some("grid".to_owned()), grid.get_attribute("role"));
+
+        let rows: Vec<String> = grid
+            .rows()
+            .map(|row| {
+                rendered
+                    .within(&row)
+                    .assert_all_by_selector::<HtmlElement>("[role=columnheader], [role=gridcell]")
+                    .map(|cell| cell.inner_text())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        assert_eq!(
+            vec!["Name, Size", "report.pdf, 2 MB", "notes.md, 4 KB"],
+            rows
+        );
+
+        let cell: HtmlElement = grid.assert_by_aria_role(AriaRole::GridCell, "notes.md");
+        assert_eq!("notes.md", cell.inner_text());
+    }
+
+    #[wasm_bindgen_test]
+    fn within_group_by_legend() {
+        let rendered = QueryElement::render_html(
+            r#"
+            <fieldset>
+                <legend>Billing</legend>
+                <label>Street <input id="billing-street" type="text"></label>
+            </fieldset>
+            <fieldset>
+                <legend>Shipping</legend>
+                <label>Street <input id="shipping-street" type="text"></label>
+            </fieldset>
+        "#,
+        );
+
+        let shipping = rendered.within_group("Shipping").unwrap();
+        assert_eq!("FIELDSET", shipping.tag_name());
+
+        let street: HtmlInputElement = shipping.assert_by_aria_role(AriaRole::TextBox, "Street");
+        assert_eq!("shipping-street", street.id());
+
+        assert!(rendered.within_group("Contact").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn within_result_scopes_to_found_card() -> Result<(), Error> {
+        let rendered = QueryElement::render_html(
+            r#"
+            <article aria-label="Basic plan"><button>Choose</button></article>
+            <article aria-label="Pro plan"><button id="choose-pro">Choose</button></article>
+        "#,
+        );
+
+        let choose: HtmlButtonElement = rendered
+            .within_result(rendered.get_by_aria_role(AriaRole::Article, "Pro plan"))?
+            .get_by_aria_role(AriaRole::Button, "Choose")?;
+        assert_eq!("choose-pro", choose.id());
+
+        assert!(rendered
+            .within_result(rendered.get_by_aria_role(AriaRole::Article, "Enterprise plan"))
+            .is_err());
+        Ok(())
     }
 }
@@ -0,0 +1,114 @@
+use std::{cell::RefCell, rc::Rc};
+
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{Element, Event, HtmlElement};
+
+/// The event types that are logged by [`QueryElement::trace`](super::QueryElement::trace).
+const TRACED_EVENT_TYPES: &[&str] = &[
+    "animationend",
+    "blur",
+    "change",
+    "click",
+    "dblclick",
+    "focus",
+    "input",
+    "keydown",
+    "keypress",
+    "keyup",
+    "mousedown",
+    "mouseup",
+    "reset",
+    "submit",
+    "transitionend",
+];
+
+/// An event that was fired within a traced [`QueryElement`](super::QueryElement).
+#[derive(Clone, Debug, PartialEq)]
+pub struct TracedEvent {
+    /// The type of the event, for example `"click"`.
+    pub event_type: String,
+    /// The path from the root element to the target of the event, for example
+    /// `"form > button#save"`.
+    pub target: String,
+}
+
+/// Capturing listeners installed on the root element which log every traced event type.
+pub(crate) struct EventTrace {
+    root: HtmlElement,
+    events: Rc<RefCell<Vec<TracedEvent>>>,
+    listener: Closure<dyn Fn(Event)>,
+}
+
+impl EventTrace {
+    pub(crate) fn install(root: &HtmlElement) -> Self {
+        let events: Rc<RefCell<Vec<TracedEvent>>> = Default::default();
+        let listener = {
+            let root = root.clone();
+            let events = events.clone();
+            Closure::wrap(Box::new(move |event: Event| {
+                let target = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .map(|target| target_path(&root, &target))
+                    .unwrap_or_default();
+                events.borrow_mut().push(TracedEvent {
+                    event_type: event.type_(),
+                    target,
+                });
+            }) as Box<dyn Fn(Event)>)
+        };
+
+        for event_type in TRACED_EVENT_TYPES {
+            root.add_event_listener_with_callback_and_bool(
+                event_type,
+                listener.as_ref().unchecked_ref(),
+                true,
+            )
+            .unwrap();
+        }
+
+        Self {
+            root: root.clone(),
+            events,
+            listener,
+        }
+    }
+
+    pub(crate) fn events(&self) -> Vec<TracedEvent> {
+        self.events.borrow().clone()
+    }
+}
+
+impl Drop for EventTrace {
+    fn drop(&mut self) {
+        for event_type in TRACED_EVENT_TYPES {
+            self.root
+                .remove_event_listener_with_callback_and_bool(
+                    event_type,
+                    self.listener.as_ref().unchecked_ref(),
+                    true,
+                )
+                .unwrap();
+        }
+    }
+}
+
+/// Selector like path from the root, which isn't included, to the target element.
+fn target_path(root: &HtmlElement, target: &Element) -> String {
+    let mut path = vec![];
+    let mut current = Some(target.clone());
+    while let Some(element) = current {
+        if root.is_same_node(Some(&element)) {
+            break;
+        }
+        let mut segment = element.tag_name().to_lowercase();
+        if !element.id().is_empty() {
+            segment.push('#');
+            segment.push_str(&element.id());
+        }
+        path.push(segment);
+        current = element.parent_element();
+    }
+    path.reverse();
+    path.join(" > ")
+}
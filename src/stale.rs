@@ -0,0 +1,98 @@
+//! Detect and recover from stale element references.
+//!
+//! An element returned by a query is a reference to a node in the DOM at the time of the query.
+//! When a component re-renders, the node might be replaced, which leaves the reference
+//! pointing to a node that is no longer in the document - a stale element. Assertions on a stale
+//! element will not reflect what the user sees.
+//!
+//! Use [`is_stale`] to check a reference, or [`refetch`] to re-run the query when the reference
+//! has gone stale.
+use wasm_bindgen::JsCast;
+use web_sys::Node;
+
+use hyphae::Error;
+
+/// Returns `true` when the element is no longer connected to the document.
+///
+/// # Examples
+/// ```no_run
+/// use hyphae::{prelude::*, stale::is_stale};
+/// use web_sys::HtmlElement;
+///
+/// let rendered = QueryElement::new();
+/// let item: HtmlElement = rendered.assert_by_text("Gardening");
+/// // .. an action that re-renders the list
+/// assert!(is_stale(&item));
+/// ```
+pub fn is_stale<T: JsCast>(element: &T) -> bool {
+    !element.unchecked_ref::<Node>().is_connected()
+}
+
+/// Returns the element when it is still connected to the document, otherwise the `query` is run
+/// again to get the new element.
+///
+/// This is useful in loops that perform an action, which might cause a re-render, and then read
+/// the element again.
+///
+/// # Examples
+/// ```no_run
+/// use hyphae::{prelude::*, stale::refetch};
+/// use web_sys::HtmlElement;
+///
+/// let rendered = QueryElement::new();
+/// let mut count: HtmlElement = rendered.assert_by_text("0");
+/// for _ in 0..3 {
+///     // .. click increment which may re-render the count
+///     count = refetch(count, || rendered.get_first_by_selector("#count")).unwrap();
+/// }
+/// ```
+pub fn refetch<T, F>(element: T, query: F) -> Result<T, Error>
+where
+    T: JsCast,
+    F: FnOnce() -> Result<T, Error>,
+{
+    if is_stale(&element) {
+        query()
+    } else {
+        Ok(element)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    use hyphae::prelude::*;
+    use hyphae_utils::make_element_with_html_string;
+
+    use web_sys::HtmlElement;
+
+    #[wasm_bindgen_test]
+    fn detect_stale_reference_after_re_render() {
+        let rendered: QueryElement = make_element_with_html_string(
+            r#"
+            <p id="count">0</p>
+        "#,
+        )
+        .into();
+
+        let count: HtmlElement = rendered.assert_by_text("0");
+        assert!(!is_stale(&count));
+
+        // re-render replaces the nodes
+        rendered.set_inner_html(r#"<p id="count">1</p>"#);
+        assert!(is_stale(&count));
+        assert_text_content!("0", count);
+
+        let count = refetch(count, || rendered.get_first_by_selector("#count")).unwrap();
+        assert!(!is_stale(&count));
+        assert_text_content!("1", count);
+
+        // a connected element is returned without running the query
+        let count = refetch(count, || panic!("should not query again")).unwrap();
+        assert_text_content!("1", count);
+    }
+}